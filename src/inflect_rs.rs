@@ -54,7 +54,7 @@ pub fn make_pl_si_lists(
         .iter()
         .map(|w| {
             if let Some(size) = si_ending_size {
-                format!("{}{}", &w[..w.len() - (size as usize)], pl_ending)
+                format!("{}{}", &w[..w.len() - (-size as usize)], pl_ending)
             } else {
                 format!("{}{}", w, pl_ending)
             }
@@ -129,6 +129,10 @@ fn pl_sb_irregular() -> HashMap<String, String> {
     pl_sb_irregular
 }
 
+fn pl_sb_irregular_archaic() -> Vec<&'static str> {
+    vec!["brethren", "kine", "turves", "beeves"]
+}
+
 fn pl_sb_irregular_caps() -> HashMap<&'static str, &'static str> {
     return vec![
         ("Romany", "Romanies"),
@@ -157,10 +161,10 @@ fn si_sb_irregular() -> HashMap<String, String> {
         }
     }
     for k in keys_to_remove {
-        si_sb_irregular.remove(k);
+        let singular = si_sb_irregular.remove(k).unwrap();
         let (k1, k2) = k.split_once('|').unwrap();
-        si_sb_irregular.insert(k1.to_string(), k.clone());
-        si_sb_irregular.insert(k2.to_string(), k.clone());
+        si_sb_irregular.insert(k1.to_string(), singular.clone());
+        si_sb_irregular.insert(k2.to_string(), singular);
     }
     si_sb_irregular
 }
@@ -184,10 +188,10 @@ fn si_sb_irregular_compound() -> HashMap<&'static str, &'static str> {
         }
     }
     for k in keys_to_remove {
-        si_sb_irregular_compound.remove(&k);
+        let singular = si_sb_irregular_compound.remove(&k).unwrap();
         let (k1, k2) = k.split_once('|').unwrap();
-        si_sb_irregular_compound.insert(k1, k);
-        si_sb_irregular_compound.insert(k2, k);
+        si_sb_irregular_compound.insert(k1, singular);
+        si_sb_irregular_compound.insert(k2, singular);
     }
     si_sb_irregular_compound
 }
@@ -1056,7 +1060,7 @@ fn pl_sb_u_man_mans_caps_list() -> Vec<String> {
         "Alabaman",
         "Bahaman",
         "Burman",
-        "|German",
+        "German",
         "Hiroshiman",
         "Liman",
         "Nakayaman",
@@ -1216,6 +1220,16 @@ fn pl_sb_uninflected_herd() -> Vec<String> {
     .collect();
 }
 
+/// True if the last word of `lowered` is a herd animal, including the hyphenated and
+/// spaced forms the table lists ("guinea-fowl", "guinea fowl").
+fn is_uninflected_herd(lowered: &str) -> bool {
+    pl_sb_uninflected_herd().iter().any(|herd| {
+        lowered == herd
+            || lowered.ends_with(&format!(" {}", herd))
+            || lowered.ends_with(&format!("-{}", herd))
+    })
+}
+
 fn pl_sb_uninflected_complete() -> Vec<String> {
     return vec![
         "tuna",
@@ -1240,7 +1254,7 @@ fn pl_sb_uninflected_complete() -> Vec<String> {
     ]
     .iter()
     .map(|s| s.to_string())
-    .chain(pl_sb_uninflected_complete())
+    .chain(pl_sb_uninflected_s_complete())
     .collect();
 }
 
@@ -1855,24 +1869,43 @@ fn plverb_special_s() -> String {
 
 fn _pl_sb_postfix_adj_defn() -> HashMap<String, String> {
     let mut m = HashMap::new();
-    m.insert(
-        "general".to_string(),
-        enclose(r"(?!major|lieutenant|brigadier|adjutant|.*star)\S+"),
-    );
+    m.insert("general".to_string(), enclose(r"\S+"));
     m.insert("martial".to_string(), enclose("court"));
     m.insert("force".to_string(), enclose("pound"));
     m
 }
 
-fn pl_sb_postfix_adj() -> Vec<String> {
+/// Nouns that never take a postfix "general" ("major general" is not a kind of major).
+/// Kept separate from the definitions because `regex` has no negative lookahead.
+fn pl_sb_postfix_adj_general_exclusions() -> Regex {
+    Regex::new(r"(?i)^(?:major|lieutenant|brigadier|adjutant|.*star)")
+        .expect("Failed to compile regex")
+}
+
+/// One pattern per postfix adjective, capturing the noun and everything from the separator on.
+fn pl_sb_postfix_adj() -> Vec<(String, Regex)> {
     return _pl_sb_postfix_adj_defn()
         .iter()
-        .map(|(k, v)| format!("{}(?=(?:-|\\s+){})", v, k))
+        .map(|(k, v)| {
+            let re = Regex::new(&format!(r"(?i)^({})((?:-|\s+){}.*)$", v, k))
+                .expect("Failed to compile regex");
+            (k.clone(), re)
+        })
         .collect();
 }
 
-fn pl_sb_postfix_adj_stems() -> String {
-    return format!("({})(.*)", pl_sb_postfix_adj().join("|"));
+/// Splits a compound with a postfix adjective ("Governor General") into its noun and the rest.
+fn pl_sb_postfix_adj_stems(word: &str) -> Option<(String, String)> {
+    for (adj, re) in pl_sb_postfix_adj() {
+        if let Some(caps) = re.captures(word) {
+            let noun = caps[1].to_string();
+            if adj == "general" && pl_sb_postfix_adj_general_exclusions().is_match(&noun) {
+                continue;
+            }
+            return Some((noun, caps[2].to_string()));
+        }
+    }
+    None
 }
 
 fn si_sb_es_is() -> Vec<String> {
@@ -2372,6 +2405,15 @@ fn nth() -> HashMap<u32, String> {
     .collect();
 }
 
+/// The ordinal suffix for `n`: "st" for 1 and 21, but "th" for 11.
+fn nth_suffix(n: u128) -> String {
+    let nth = nth();
+    nth.get(&((n % 100) as u32))
+        .or_else(|| nth.get(&((n % 10) as u32)))
+        .cloned()
+        .unwrap_or_default()
+}
+
 fn nth_suff() -> HashSet<String> {
     return nth().values().cloned().collect();
 }
@@ -2453,9 +2495,66 @@ fn mill() -> Vec<String> {
     ]
     .iter()
     .map(|s| s.to_string())
+    .chain(extended_illions())
     .collect();
 }
 
+/// Multiplicative adjectives, indexed by their number ("double" = 2).
+fn tuple_words() -> Vec<String> {
+    [
+        "",
+        "single",
+        "double",
+        "triple",
+        "quadruple",
+        "quintuple",
+        "sextuple",
+        "septuple",
+        "octuple",
+        "nonuple",
+        "decuple",
+    ]
+    .iter()
+    .map(|s| s.to_string())
+    .collect()
+}
+
+/// Collective nouns for performers, indexed by their number ("trio" = 3).
+fn group_words() -> Vec<String> {
+    [
+        "", "solo", "duo", "trio", "quartet", "quintet", "sextet", "septet", "octet", "nonet",
+        "dectet",
+    ]
+    .iter()
+    .map(|s| s.to_string())
+    .collect()
+}
+
+/// Short-scale names from undecillion (10^36) up to centillion (10^303).
+fn extended_illions() -> Vec<String> {
+    let units = [
+        "", "un", "duo", "tre", "quattuor", "quin", "sex", "septen", "octo", "novem",
+    ];
+    let tens = [
+        "dec",
+        "vigint",
+        "trigint",
+        "quadragint",
+        "quinquagint",
+        "sexagint",
+        "septuagint",
+        "octogint",
+        "nonagint",
+    ];
+    let mut names: Vec<String> = tens
+        .iter()
+        .flat_map(|t| units.iter().map(move |u| format!(" {}{}illion", u, t)))
+        .skip(1)
+        .collect();
+    names.push(" centillion".to_string());
+    names
+}
+
 fn def_classical() -> HashMap<String, bool> {
     return vec![
         ("all", false),
@@ -2496,210 +2595,4120 @@ fn dollar_digits() -> Regex {
     Regex::new("\\$(\\d+)").expect("Failed to compile Regex")
 }
 
-// TODO: Pre-compiled REGEX objects, ln1950 @ og inflect
+fn whitespaces_comma() -> Regex {
+    Regex::new(r"\s+,").expect("Failed to compile Regex")
+}
 
-pub struct Words {
-    pub lowered: String,
-    pub split_: Vec<String>,
-    pub first: String,
-    pub last: String,
+fn comma_word() -> Regex {
+    Regex::new(r", (\S+)\s+\z").expect("Failed to compile Regex")
 }
 
-impl Words {
-    pub fn new(s: &str) -> Words {
-        let split: Vec<String> = s.split_whitespace().map(String::from).collect();
-        Words {
-            lowered: s.to_lowercase(),
-            split_: split.clone(),
-            first: split.get(0).cloned().unwrap_or_else(String::new),
-            last: split.last().cloned().unwrap_or_else(String::new),
-        }
+/// A number in running text, with an optional sign, currency symbol, ratio or clock
+/// time parts (":1"), and suffix.
+fn number_token() -> Regex {
+    Regex::new(
+        r"(-?)([$£€¥₹]?)(-?)(\d+(?:,\d+)+(?:\.\d+)?|\d*\.\d+|\d+)((?::\d+)+)?(st|nd|rd|th|%)?",
+    )
+    .expect("Failed to compile Regex")
+}
+
+fn month_names() -> Vec<String> {
+    [
+        "january",
+        "february",
+        "march",
+        "april",
+        "may",
+        "june",
+        "july",
+        "august",
+        "september",
+        "october",
+        "november",
+        "december",
+    ]
+    .iter()
+    .map(|s| s.to_string())
+    .collect()
+}
+
+/// The number of days in a month of the proleptic Gregorian calendar.
+fn days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        4 | 6 | 9 | 11 => 30,
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        _ => 31,
     }
 }
 
-// FIXME: This is terrible. Placeholder.
-#[derive(Debug, Clone)]
-pub struct Word(String);
+/// The currency code written with a symbol in running text ("$5").
+fn currency_symbol_code(symbol: &str) -> Option<&'static str> {
+    match symbol {
+        "$" => Some("USD"),
+        "£" => Some("GBP"),
+        "€" => Some("EUR"),
+        "¥" => Some("JPY"),
+        "₹" => Some("INR"),
+        _ => None,
+    }
+}
 
-impl Word {
-    pub fn new(word: String) -> Result<Self, &'static str> {
-        if word.len() >= 1 {
-            Ok(Word(word))
-        } else {
-            Err("Word must be >= 1 chars long.")
-        }
+/// Whether a four-digit number is probably a year, judging by the words around it:
+/// "in 1984", "March 3, 1999", "44 BC".
+fn year_context(before: &str, after: &str) -> bool {
+    let bare = |w: &str| w.trim_matches(|c: char| !c.is_alphanumeric()).to_string();
+    let mut previous = before.split_whitespace().rev().map(bare);
+    let prev = previous.next().unwrap_or_default();
+    let prev2 = previous.next().unwrap_or_default();
+    let next = after
+        .split_whitespace()
+        .next()
+        .map(bare)
+        .unwrap_or_default();
+    // Month names must be capitalized, so "may 2000 people" is not a date.
+    let is_month = |w: &str| {
+        let lowered = w.to_lowercase();
+        starts_upper(w)
+            && lowered.len() >= 3
+            && month_names().iter().any(|m| m.starts_with(&lowered))
+    };
+    let eras = ["BC", "AD", "BCE", "CE"];
+    [
+        "in", "since", "from", "until", "till", "by", "during", "circa", "year",
+    ]
+    .contains(&prev.to_lowercase().as_str())
+        || eras.contains(&prev.as_str())
+        || eras.contains(&next.as_str())
+        || is_month(&prev)
+        || (!prev.is_empty() && prev.chars().all(|c| c.is_ascii_digit()) && is_month(&prev2))
+}
+
+/// Returns the last `n` characters of `s`, or all of `s` if it is shorter.
+fn tail(s: &str, n: usize) -> &str {
+    let len = s.chars().count();
+    if n >= len {
+        return s;
+    }
+    let idx = s
+        .char_indices()
+        .nth(len - n)
+        .map(|(i, _)| i)
+        .unwrap_or(s.len());
+    &s[idx..]
+}
+
+/// Returns `s` without its last `n` characters.
+fn chop(s: &str, n: usize) -> &str {
+    let len = s.chars().count();
+    if n >= len {
+        return "";
     }
+    let idx = s
+        .char_indices()
+        .nth(len - n)
+        .map(|(i, _)| i)
+        .unwrap_or(s.len());
+    &s[..idx]
+}
 
-    pub fn get(&self) -> &str {
-        &self.0
+/// Returns `s` with its first `n` characters removed.
+fn skip(s: &str, n: usize) -> &str {
+    let idx = s.char_indices().nth(n).map(|(i, _)| i).unwrap_or(s.len());
+    &s[idx..]
+}
+
+/// True if any word in a bysize table is a suffix of `s`.
+fn ends_with_bysize(s: &str, table: &HashMap<usize, HashSet<String>>) -> bool {
+    table.iter().any(|(&k, v)| v.contains(tail(s, k)))
+}
+
+fn starts_upper(s: &str) -> bool {
+    s.chars().next().is_some_and(|c| c.is_uppercase())
+}
+
+fn capitalize(s: &str) -> String {
+    let mut chars = s.chars();
+    match chars.next() {
+        Some(c) => c
+            .to_uppercase()
+            .chain(chars.flat_map(|c| c.to_lowercase()))
+            .collect(),
+        None => String::new(),
     }
 }
 
-pub struct Engine {
-    pub classical_dict: HashMap<String, bool>,
-    pub persistent_count: Option<i32>,
-    mill_count: i32,
-    pl_sb_user_defined: Vec<Option<Word>>,
-    pl_v_user_defined: Vec<Option<Word>>,
-    pl_adj_user_defined: Vec<Option<Word>>,
-    si_sb_user_defined: Vec<Option<Word>>,
-    a_a_user_defined: Vec<Option<Word>>,
-    the_gender: String,
-    _number_args: Option<HashMap<String, String>>,
+/// Splits `text` into leading whitespace, the word itself, and trailing whitespace.
+fn partition_word(text: &str) -> (&str, &str, &str) {
+    let trimmed_start = text.trim_start();
+    let pre = &text[..text.len() - trimmed_start.len()];
+    let word = trimmed_start.trim_end();
+    let post = &trimmed_start[word.len()..];
+    (pre, word, post)
 }
 
-impl Engine {
-    pub fn new() -> Engine {
-        Engine {
-            classical_dict: def_classical(),
-            persistent_count: None,
-            mill_count: 0,
-            pl_sb_user_defined: Vec::new(),
-            pl_v_user_defined: Vec::new(),
-            pl_adj_user_defined: Vec::new(),
-            si_sb_user_defined: Vec::new(),
-            a_a_user_defined: Vec::new(),
-            the_gender: "neuter".to_string(),
-            _number_args: None,
+/// Index of the first preposition that sits between two other words.
+fn find_pivot(words: &[&str], candidates: &[String]) -> Option<usize> {
+    (1..words.len().saturating_sub(1)).find(|&i| candidates.iter().any(|c| c == words[i]))
+}
+
+/// Looks up the singular of a plural pronoun, honouring gender where the tables distinguish it.
+fn si_pron_lookup(thecase: &str, word: &str, gender: &str) -> Option<String> {
+    for (case, plur, gend, sing) in pron_tuples() {
+        if case == thecase && plur == word && gend == gender {
+            return Some(sing.to_string());
         }
     }
+    let table = match thecase {
+        "nom" => pl_pron_nom(),
+        _ => pl_pron_acc(),
+    };
+    let mut singulars: Vec<String> = table
+        .into_iter()
+        .filter(|(_, v)| v == word)
+        .map(|(k, _)| k)
+        .collect();
+    singulars.sort();
+    singulars.into_iter().next()
+}
 
-    fn _number_args(&self) -> Option<&HashMap<String, String>> {
-        self._number_args.as_ref()
-    }
+/// Which form comes first when a word has both a modern and a classical plural.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum VariantPreference {
+    /// "indexes" before "indices".
+    #[default]
+    ModernFirst,
+    /// "indices" before "indexes".
+    ClassicalFirst,
+}
 
-    fn set_number_args(&mut self, args: Option<HashMap<String, String>>) {
-        self._number_args = args;
+/// Joins the chunks of a number, separating the groups before the decimal point with `comma`.
+fn render_number_chunks(mut chunks: Vec<String>, decimal: Option<&str>, comma: &str) -> String {
+    if chunks.is_empty() {
+        return String::new();
     }
-
-    fn defnoun(&mut self, singular: &Option<Word>, plural: &Option<Word>) {
-        self.checkpat(singular);
-        self.checkpatplural(plural);
-        self.pl_sb_user_defined
-            .extend(vec![singular.clone(), plural.clone()]);
-        self.si_sb_user_defined
-            .extend(vec![plural.clone(), singular.clone()]);
+    let first_item = chunks.remove(0);
+    let mut first = decimal != Some(first_item.as_str());
+    let mut out = first_item;
+    for nc in chunks {
+        if Some(nc.as_str()) == decimal {
+            first = false;
+        } else if first {
+            out.push_str(comma);
+        }
+        out.push(' ');
+        out.push_str(&nc);
     }
+    out
+}
 
-    fn defverb(
-        &mut self,
-        singular_1st: &Option<Word>,
-        singular_2nd: &Option<Word>,
-        singular_3rd: &Option<Word>,
-        plural_1st: &Option<Word>,
-        plural_2nd: &Option<Word>,
-        plural_3rd: &Option<Word>,
-    ) {
-        self.checkpat(singular_1st);
-        self.checkpat(singular_2nd);
-        self.checkpat(singular_3rd);
-        self.checkpat(plural_1st);
-        self.checkpat(plural_2nd);
-        self.checkpat(plural_3rd);
-        self.pl_v_user_defined.extend(vec![
-            singular_1st.clone(),
-            singular_2nd.clone(),
-            singular_3rd.clone(),
-            plural_1st.clone(),
-            plural_2nd.clone(),
-            plural_3rd.clone(),
-        ]);
+/// Numbers above `opts.threshold` are written as digits ("1,234") rather than words.
+fn threshold_digits(num: &str, opts: &NumberToWordsOptions) -> Option<String> {
+    let threshold = opts.threshold?;
+    if !num.trim().parse::<f64>().is_ok_and(|n| n > threshold) {
+        return None;
     }
+    let (int, frac) = match num.split_once('.') {
+        Some((int, frac)) => (int, Some(frac)),
+        None => (num, None),
+    };
+    let int = if opts.comma.is_empty() {
+        int.to_string()
+    } else {
+        opts.numbering.group_digits(int)
+    };
+    Some(match frac {
+        Some(frac) => format!("{}.{}", int, frac),
+        None => int,
+    })
+}
+
+/// Inserts a comma between every group of three digits ("1234567" -> "1,234,567").
+fn four_digit_comma(digits: &str) -> String {
+    let chars: Vec<char> = digits.chars().collect();
+    let mut out = String::new();
+    for (i, c) in chars.iter().enumerate() {
+        let remaining = chars.len() - i;
+        if i > 0 && remaining.is_multiple_of(3) && chars[i - 1].is_ascii_digit() {
+            out.push(',');
+        }
+        out.push(*c);
+    }
+    out
+}
 
-    fn defadj(&mut self, singular: &Option<Word>, plural: &Option<Word>) {
-        self.checkpat(singular);
-        self.checkpatplural(plural);
-        self.pl_adj_user_defined
-            .extend(vec![singular.clone(), plural.clone()]);
+/// Whether `s` is plain digits with at most one decimal point ("12", "0.5", ".5").
+fn is_decimal_digits(s: &str) -> bool {
+    let (int, frac) = s.split_once('.').unwrap_or((s, ""));
+    !(int.is_empty() && frac.is_empty())
+        && int.chars().chain(frac.chars()).all(|c| c.is_ascii_digit())
+}
+
+/// Whether `digits` only uses commas between groups of three ("1,234" but not "1,00").
+fn is_comma_grouped(digits: &str) -> bool {
+    match digits.split_once(',') {
+        Some((first, rest)) => {
+            (1..=3).contains(&first.len()) && rest.split(',').all(|g| g.len() == 3)
+        }
+        None => true,
     }
+}
 
-    fn defa(&mut self, pattern: &Option<Word>) {
-        self.checkpat(pattern);
-        self.a_a_user_defined.extend(vec![
-            pattern.clone(),
-            Some(Word::new(String::from("a")).expect("Failed to make Word")),
-        ]);
+/// Whether `s` reads as a number: at most one sign, comma-grouped digits and at most one
+/// decimal point ("-1,234.5", ".5").
+fn is_number_text(s: &str) -> bool {
+    let unsigned = s.strip_prefix(['-', '+']).unwrap_or(s);
+    let (int, frac) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+    is_comma_grouped(int) && !frac.contains(',') && is_decimal_digits(&unsigned.replace(',', ""))
+}
+
+/// `s` without an ordinal suffix after its last digit ("21st" -> "21").
+fn strip_ordinal_suffix(s: &str) -> &str {
+    match s.get(s.len().saturating_sub(2)..) {
+        Some(suffix) if nth_suff().contains(suffix) => {
+            let stem = &s[..s.len() - 2];
+            if stem.ends_with(|c: char| c.is_ascii_digit()) && !stem.contains('.') {
+                stem
+            } else {
+                s
+            }
+        }
+        _ => s,
     }
+}
 
-    fn defan(&mut self, pattern: &Option<Word>) {
-        self.checkpat(pattern);
-        self.a_a_user_defined.extend(vec![
-            pattern.clone(),
-            Some(Word::new(String::from("an")).expect("Failed to make Word")),
-        ]);
+/// Removes zeros from the end of a decimal ("2.50" -> "2.5", "3.00" -> "3").
+fn trim_decimal_zeros(num: &str) -> &str {
+    match num.split_once('.') {
+        Some((int, _)) => {
+            let trimmed = num.trim_end_matches('0');
+            match trimmed.strip_suffix('.') {
+                Some(_) if int.is_empty() => "0",
+                Some(_) => int,
+                None => trimmed,
+            }
+        }
+        None => num,
     }
+}
 
-    fn checkpat(&self, pattern: &Option<Word>) {
-        if pattern.is_none() {
-            return;
+/// The name of the `places`th decimal place: "tenth", "hundredth", "ten-thousandth".
+fn decimal_place_name(places: usize) -> Result<String, InflectError> {
+    match places {
+        1 => return Ok("tenth".to_string()),
+        2 => return Ok("hundredth".to_string()),
+        _ => {}
+    }
+    let prefix = ["", "ten-", "hundred-"][places % 3];
+    let mill = mill();
+    let scale = mill.get(places / 3).ok_or(InflectError::NumOutOfRange)?;
+    Ok(format!("{}{}th", prefix, scale.trim()))
+}
+
+/// Adds one to a string of digits ("199" -> "200").
+fn increment_digits(digits: &str) -> String {
+    let mut out: Vec<u8> = digits.bytes().collect();
+    for d in out.iter_mut().rev() {
+        if *d == b'9' {
+            *d = b'0';
+        } else {
+            *d += 1;
+            return String::from_utf8(out).unwrap_or_default();
         }
+    }
+    format!("1{}", String::from_utf8(out).unwrap_or_default())
+}
+
+/// Splits a decimal string into its sign, integer digits and `places` fractional digits,
+/// rounding whatever is cut off. Returns None if `amount` is not a plain decimal.
+fn round_decimal(
+    amount: &str,
+    places: usize,
+    rounding: Rounding,
+) -> Option<(bool, String, String)> {
+    let amount = amount.trim();
+    let (negative, unsigned) = match amount.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, amount.strip_prefix('+').unwrap_or(amount)),
+    };
+    let (int, frac) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+    if int.is_empty() && frac.is_empty()
+        || !int.chars().chain(frac.chars()).all(|c| c.is_ascii_digit())
+    {
+        return None;
+    }
+    let mut kept = frac.chars().take(places).collect::<String>();
+    while kept.len() < places {
+        kept.push('0');
+    }
+    let dropped = frac.get(places..).unwrap_or("");
+    let digits = format!("{}{}", int, kept);
+    let round_up = match rounding {
+        Rounding::Down => false,
+        Rounding::HalfUp => dropped.starts_with(|c: char| c >= '5'),
+        Rounding::HalfEven => match dropped.chars().next() {
+            Some(c) if c > '5' => true,
+            Some('5') if dropped[1..].chars().any(|c| c != '0') => true,
+            Some('5') => digits.ends_with(['1', '3', '5', '7', '9']),
+            _ => false,
+        },
+    };
+    let digits = if round_up {
+        increment_digits(&digits)
+    } else {
+        digits
+    };
+    let digits = format!("{:0>width$}", digits, width = places + 1);
+    let (int, minor) = digits.split_at(digits.len() - places);
+    let int = int.trim_start_matches('0');
+    let int = if int.is_empty() { "0" } else { int };
+    Some((negative, int.to_string(), minor.to_string()))
+}
+
+/// Roman numeral symbols by value, largest first, in ASCII and Unicode forms.
+/// The apostrophus forms above 500 use the reversed C, "Ↄ".
+fn roman_symbols() -> Vec<(u64, String, String)> {
+    [
+        (100000, "CCCIↃↃↃ", "ↈ"),
+        (50000, "IↃↃↃ", "ↇ"),
+        (10000, "CCIↃↃ", "ↂ"),
+        (5000, "IↃↃ", "ↁ"),
+        (1000, "M", "Ⅿ"),
+        (500, "D", "Ⅾ"),
+        (100, "C", "Ⅽ"),
+        (50, "L", "Ⅼ"),
+        (10, "X", "Ⅹ"),
+        (5, "V", "Ⅴ"),
+        (1, "I", "Ⅰ"),
+    ]
+    .iter()
+    .map(|&(v, ascii, unicode)| (v, ascii.to_string(), unicode.to_string()))
+    .collect()
+}
 
-        if pattern.is_some() {
-            let word = pattern.clone().expect("Failed to unwrap Word");
-            let _re = Regex::new(word.get()).expect("Failed to compile regex");
+/// Writes `n` with subtractive notation, using only symbols no larger than `top`.
+fn roman_digits(mut n: u64, top: u64, unicode: bool) -> String {
+    let symbols: Vec<(u64, String)> = roman_symbols()
+        .into_iter()
+        .filter(|&(v, _, _)| v <= top)
+        .map(|(v, ascii, uni)| (v, if unicode { uni } else { ascii }))
+        .collect();
+    let mut out = String::new();
+    for (i, (value, symbol)) in symbols.iter().enumerate() {
+        while n >= *value {
+            out.push_str(symbol);
+            n -= value;
+        }
+        // The subtractive pair uses the next power of ten down: IV, IX, XL, XC, CD, CM.
+        let Some((smaller, prefix)) = symbols[i + 1..]
+            .iter()
+            .find(|(v, _)| 10u64.pow(v.ilog10()) == *v)
+            .filter(|(v, _)| *v * 10 >= *value && *v < *value)
+        else {
+            continue;
+        };
+        if n >= value - smaller {
+            out.push_str(prefix);
+            out.push_str(symbol);
+            n -= value - smaller;
         }
     }
+    out
+}
 
-    // TODO: Check for replace pattern.
-    fn checkpatplural(&self, pattern: &Option<Word>) {
-        self.checkpat(pattern);
+/// The value of each Roman symbol in `text`, longest symbol first, or None if any part
+/// of it is not a numeral. A combining overline multiplies by 1000.
+fn roman_values(text: &str) -> Option<Vec<u64>> {
+    let upper = text.to_uppercase().replace('ↄ', "Ↄ");
+    let mut tokens: Vec<(String, u64)> = vec![("CIↃ".to_string(), 1000), ("IↃ".to_string(), 500)];
+    for (value, ascii, unicode) in roman_symbols() {
+        tokens.push((ascii, value));
+        tokens.push((unicode, value));
+    }
+    for (i, c) in "ⅠⅡⅢⅣⅤⅥⅦⅧⅨⅩⅪⅫ".chars().enumerate() {
+        tokens.push((c.to_string(), i as u64 + 1));
+    }
+    tokens.push(("ↀ".to_string(), 1000));
+    tokens.sort_by_key(|(t, _)| std::cmp::Reverse(t.chars().count()));
+
+    let mut values = Vec::new();
+    let mut rest = upper.as_str();
+    while !rest.is_empty() {
+        let (token, value) = tokens.iter().find(|(t, _)| rest.starts_with(t.as_str()))?;
+        rest = &rest[token.len()..];
+        match rest.strip_prefix('\u{305}') {
+            Some(after) => {
+                rest = after;
+                values.push(value * 1000);
+            }
+            None => values.push(*value),
+        }
     }
+    Some(values)
+}
 
-    pub fn gender(&mut self, gender: &str) {
-        if singular_pronoun_genders().contains(&String::from(gender)) {
-            self.the_gender = gender.to_string();
+/// Uppercases the first letter of every word, including each part of a hyphenated word.
+fn title_case(s: &str) -> String {
+    let mut out = String::new();
+    let mut word_start = true;
+    for c in s.chars() {
+        if word_start {
+            out.extend(c.to_uppercase());
+        } else {
+            out.push(c);
         }
+        word_start = !c.is_alphanumeric();
+    }
+    out
+}
+
+/// Uppercases the first letter of `s`, leaving the rest alone.
+fn capitalize_first(s: &str) -> String {
+    let mut chars = s.chars();
+    match chars.next() {
+        Some(c) => c.to_uppercase().chain(chars).collect(),
+        None => String::new(),
     }
+}
 
-    pub fn check_gender(&self) -> &String {
-        &self.the_gender
+/// Rounds a string of digits to `significant` digits, keeping its length unless the
+/// rounding carries ("9960" to 2 digits is "10000").
+fn round_significant(digits: &str, significant: usize, rounding: Rounding) -> String {
+    let significant = significant.max(1);
+    if digits.len() <= significant {
+        return digits.to_string();
+    }
+    let zeros = digits.len() - significant;
+    let (_, int, _) = round_decimal(
+        &format!("{}.{}", &digits[..significant], &digits[significant..]),
+        0,
+        rounding,
+    )
+    .unwrap_or_default();
+    format!("{}{}", int, "0".repeat(zeros))
+}
+
+/// The value of a single digit word, including the spoken forms of zero.
+fn digit_word_value(word: &str) -> Option<i128> {
+    match word {
+        "zero" | "oh" | "nil" | "nought" => Some(0),
+        _ => unit()
+            .iter()
+            .position(|u| u == word)
+            .filter(|&i| i > 0)
+            .map(|i| i as i128),
     }
+}
 
-    pub fn get_count<T: Into<IntOrString>>(&self, count: Option<T>) -> i32 {
-        if count.is_none() {
-            if self.persistent_count.is_some() {
-                return self.persistent_count.unwrap();
-            } else {
-                return 0;
+/// Sums the value of a sequence of cardinal number words. Fails with `NumOutOfRange` when
+/// the value does not fit an `i128` ("one centillion").
+fn words_to_integer(tokens: &[&str]) -> Result<i128, InflectError> {
+    let bad = || InflectError::BadNumValue(tokens.join(" "));
+    let scales: Vec<String> = mill().iter().map(|m| m.trim().to_string()).collect();
+    let mut total: i128 = 0;
+    let mut current: i128 = 0;
+    // What the last token was, to reject sequences like "twenty thirty" or "one two".
+    let mut last = "";
+    // Scale words must get smaller: "one million two thousand", not "one thousand two million".
+    let mut last_scale = usize::MAX;
+    for (i, &token) in tokens.iter().enumerate() {
+        if token == "and" {
+            if last.is_empty() || last == "and" || i + 1 == tokens.len() {
+                return Err(bad());
             }
+            last = "and";
+            continue;
         }
-
-        let c = count.unwrap().into();
-        match c {
-            IntOrString::Int(n) => return n,
-            IntOrString::Str(s) => {
-                if pl_count_one().contains(&s)
-                    || (*self.classical_dict.get("zero").unwrap_or(&false)
-                        && pl_count_zero().contains(&s.to_lowercase()))
-                {
-                    return 1;
-                } else {
-                    return 2;
-                }
+        if token == "a" {
+            if !matches!(tokens.get(i + 1), Some(&t) if t == "hundred" || scales[1..].iter().any(|s| s == t))
+            {
+                return Err(bad());
+            }
+            current = 1;
+            last = "unit";
+            continue;
+        }
+        if let Some(d) = digit_word_value(token) {
+            // Zero only stands alone: "zero", not "one hundred and zero".
+            if d == 0 && tokens.len() > 1 {
+                return Err(bad());
+            }
+            if last == "unit" || last == "teen" {
+                return Err(bad());
+            }
+            current += d;
+            last = "unit";
+        } else if let Some(t) = teen().iter().position(|w| w == token) {
+            if !last.is_empty() && last != "hundred" && last != "scale" && last != "and" {
+                return Err(bad());
+            }
+            current += 10 + t as i128;
+            last = "teen";
+        } else if let Some(t) = ten().iter().position(|w| w == token).filter(|&t| t >= 2) {
+            if !last.is_empty() && last != "hundred" && last != "scale" && last != "and" {
+                return Err(bad());
             }
+            current += 10 * t as i128;
+            last = "ten";
+        } else if token == "hundred" {
+            if current >= 100 || last == "hundred" {
+                return Err(bad());
+            }
+            current = current.max(1) * 100;
+            last = "hundred";
+        } else if let Some(s) = scales.iter().position(|w| w == token).filter(|&s| s >= 1) {
+            if last == "scale" || last.is_empty() || s >= last_scale {
+                return Err(bad());
+            }
+            last_scale = s;
+            total = 1000i128
+                .checked_pow(s as u32)
+                .and_then(|scale| current.max(1).checked_mul(scale))
+                .and_then(|n| total.checked_add(n))
+                .ok_or(InflectError::NumOutOfRange)?;
+            current = 0;
+            last = "scale";
+        } else {
+            return Err(bad());
+        }
+    }
+    total
+        .checked_add(current)
+        .ok_or(InflectError::NumOutOfRange)
+}
+
+/// Errors raised by the number conversions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InflectError {
+    /// `group` must be between 0 and 3.
+    BadChunkingOption,
+    /// The number has more digit groups than there are scale words to name them.
+    NumOutOfRange,
+    /// The input could not be read as a number.
+    BadNumValue(String),
+}
+
+impl std::fmt::Display for InflectError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InflectError::BadChunkingOption => write!(f, "group must be between 0 and 3"),
+            InflectError::NumOutOfRange => write!(f, "number is too large to convert to words"),
+            InflectError::BadNumValue(s) => write!(f, "could not read {:?} as a number", s),
         }
     }
 }
 
-enum IntOrString {
-    Int(i32),
-    Str(String),
+impl std::error::Error for InflectError {}
+
+/// A number read back from words.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Number {
+    Int(i128),
+    Float(f64),
 }
 
-impl From<i32> for IntOrString {
-    fn from(n: i32) -> Self {
-        IntOrString::Int(n)
+impl std::fmt::Display for Number {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Number::Int(n) => write!(f, "{}", n),
+            Number::Float(n) => write!(f, "{}", n),
+        }
     }
 }
 
-impl From<String> for IntOrString {
-    fn from(s: String) -> Self {
-        IntOrString::Str(s)
+/// Options for `Engine::number_to_words`, mirroring the keyword arguments of the Python library.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NumberToWordsOptions {
+    /// Read digits in groups of 1, 2 or 3 ("one two three"), or 0 for a normal reading.
+    pub group: usize,
+    pub comma: String,
+    pub andword: String,
+    pub zero: String,
+    pub one: String,
+    /// The word for the decimal point, or None to ignore the point entirely.
+    pub decimal: Option<String>,
+    /// Numbers above the threshold are returned as digits, comma-grouped per `numbering`.
+    pub threshold: Option<f64>,
+    /// What to do with numbers too large for the last scale name (centillion).
+    pub scale_overflow: ScaleOverflow,
+    /// Which names and digit grouping to use for large numbers.
+    pub numbering: NumberingSystem,
+    /// How the digits after the decimal point are read.
+    pub decimal_style: DecimalStyle,
+    /// Drop zeros at the end of the decimal part, so "2.50" reads as "two point five".
+    pub trim_trailing_zeros: bool,
+}
+
+impl Default for NumberToWordsOptions {
+    fn default() -> Self {
+        NumberToWordsOptions {
+            group: 0,
+            comma: ",".to_string(),
+            andword: "and".to_string(),
+            zero: "zero".to_string(),
+            one: "one".to_string(),
+            decimal: Some("point".to_string()),
+            threshold: None,
+            scale_overflow: ScaleOverflow::default(),
+            numbering: NumberingSystem::default(),
+            decimal_style: DecimalStyle::default(),
+            trim_trailing_zeros: false,
+        }
+    }
+}
+
+/// Handling of numbers past the largest named scale.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ScaleOverflow {
+    /// Return `InflectError::NumOutOfRange`.
+    #[default]
+    Error,
+    /// Stack scale names, as in "one thousand centillion".
+    Compound,
+}
+
+/// Reading of the digits after a decimal point.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DecimalStyle {
+    /// "three point one four".
+    #[default]
+    DigitByDigit,
+    /// "three and fourteen hundredths". Only used when `group` is 0.
+    PlaceValue,
+}
+
+/// The names given to large numbers.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum NumberingSystem {
+    /// billion = 10^9, trillion = 10^12.
+    #[default]
+    ShortScale,
+    /// milliard = 10^9, billion = 10^12, billiard = 10^15.
+    LongScale,
+    /// thousand, lakh (10^5) and crore (10^7), grouped "1,00,00,000".
+    Indian,
+}
+
+impl NumberingSystem {
+    /// The name of each digit group, counting from the units.
+    fn scale_names(self) -> Vec<String> {
+        let mill = mill();
+        match self {
+            NumberingSystem::ShortScale => mill,
+            NumberingSystem::LongScale => mill[..2]
+                .iter()
+                .cloned()
+                .chain(
+                    mill[2..]
+                        .iter()
+                        .flat_map(|m| [m.clone(), m.replace("illion", "illiard")]),
+                )
+                .collect(),
+            NumberingSystem::Indian => [" ", " thousand", " lakh", " crore"]
+                .iter()
+                .map(|s| s.to_string())
+                .collect(),
+        }
+    }
+
+    /// Inserts commas between digit groups: "12,345,678", or "1,23,45,678" for Indian.
+    fn group_digits(self, num: &str) -> String {
+        if self != NumberingSystem::Indian {
+            return four_digit_comma(num);
+        }
+        let start = num.find(|c: char| c.is_ascii_digit()).unwrap_or(num.len());
+        let (sign, digits) = num.split_at(start);
+        if digits.len() <= 3 {
+            return num.to_string();
+        }
+        let (head, tail) = digits.split_at(digits.len() - 3);
+        let mut out = sign.to_string();
+        for (i, c) in head.chars().enumerate() {
+            if i > 0 && (head.len() - i).is_multiple_of(2) {
+                out.push(',');
+            }
+            out.push(c);
+        }
+        format!("{},{}", out, tail)
+    }
+}
+
+/// Options for `Engine::year_to_words`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct YearToWordsOptions {
+    /// How years just past a thousand (2005, 1007) are read.
+    pub millennium: MillenniumStyle,
+    /// The word in "two thousand and five", or "" for "two thousand five".
+    pub andword: String,
+    /// The suffix put on negative years.
+    pub era: Era,
+}
+
+impl Default for YearToWordsOptions {
+    fn default() -> Self {
+        YearToWordsOptions {
+            millennium: MillenniumStyle::default(),
+            andword: "and".to_string(),
+            era: Era::default(),
+        }
+    }
+}
+
+/// Reading of the years 1001-1009, 2001-2009 and so on.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum MillenniumStyle {
+    /// "two thousand and five".
+    #[default]
+    Thousand,
+    /// "twenty oh-five".
+    Pairs,
+}
+
+/// The era names used for years before year one.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Era {
+    /// "forty-four BC".
+    #[default]
+    BcAd,
+    /// "forty-four BCE".
+    BceCe,
+}
+
+/// How `Engine::to_roman` writes a numeral.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RomanStyle {
+    pub letters: RomanLetters,
+    /// How numbers of 4000 and above are written.
+    pub large: RomanLarge,
+}
+
+/// The characters used for Roman numerals.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum RomanLetters {
+    /// "XIV".
+    #[default]
+    Upper,
+    /// "xiv".
+    Lower,
+    /// The Unicode numeral characters: "ⅩⅠⅤ".
+    Unicode,
+}
+
+/// The notation for numbers of 4000 and above.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum RomanLarge {
+    /// An overline multiplies by 1000: "I̅V̅" is 4000. Goes up to 3,999,999.
+    #[default]
+    Vinculum,
+    /// The apostrophus forms, "CIↃ" for 1000 and "IↃↃ" for 5000, or "ↀ" and "ↁ" with
+    /// Unicode letters. Goes up to 399,999.
+    Apostrophus,
+}
+
+/// Options for `Engine::cheque_words`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChequeOptions {
+    /// The currency named after the whole amount ("... Fifty Dollars and 00/100"), or None
+    /// to leave it out.
+    pub currency: Option<Currency>,
+    pub casing: Casing,
+    pub rounding: Rounding,
+    /// Pad the words to this many characters with `pad`, so nothing can be added after them.
+    pub width: Option<usize>,
+    pub pad: char,
+}
+
+impl Default for ChequeOptions {
+    fn default() -> Self {
+        ChequeOptions {
+            currency: None,
+            casing: Casing::default(),
+            rounding: Rounding::HalfUp,
+            width: None,
+            pad: '*',
+        }
+    }
+}
+
+/// The letter case of cheque words.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Casing {
+    /// "One Thousand Twenty-Five".
+    #[default]
+    Title,
+    /// "ONE THOUSAND TWENTY-FIVE".
+    Upper,
+}
+
+/// A house style's rules for when `Engine::format_number` spells a number out.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NumberStyle {
+    /// Spell out whole numbers up to and including this value.
+    pub spell_up_to: u64,
+    /// Also spell out round numbers: a spelled-out number of hundreds, thousands, millions
+    /// and so on ("thirty-five thousand").
+    pub spell_round: bool,
+    /// Put commas in numbers written as digits ("12,500").
+    pub group_digits: bool,
+    /// How spelled-out numbers are written.
+    pub number: NumberToWordsOptions,
+}
+
+impl NumberStyle {
+    /// Associated Press: spell out zero through nine.
+    pub fn ap() -> NumberStyle {
+        NumberStyle::custom(9)
+    }
+
+    /// Chicago Manual of Style: spell out zero through one hundred, and round numbers.
+    pub fn chicago() -> NumberStyle {
+        NumberStyle {
+            spell_round: true,
+            ..NumberStyle::custom(100)
+        }
+    }
+
+    /// Spell out numbers up to `spell_up_to`, with American wording ("one hundred one").
+    pub fn custom(spell_up_to: u64) -> NumberStyle {
+        NumberStyle {
+            spell_up_to,
+            spell_round: false,
+            group_digits: true,
+            number: NumberToWordsOptions {
+                andword: "".to_string(),
+                comma: "".to_string(),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+/// Where a number falls in its sentence.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Position {
+    #[default]
+    Inline,
+    /// Numbers starting a sentence are always spelled out and capitalized.
+    SentenceStart,
+}
+
+/// Options for `Engine::approx_number`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ApproxOptions {
+    /// How many significant digits to keep: 2 gives "1.2 million".
+    pub significant: usize,
+    pub rounding: Rounding,
+    pub hedge: Hedge,
+    /// The noun counted, pluralized to agree with the number.
+    pub noun: Option<String>,
+}
+
+impl Default for ApproxOptions {
+    fn default() -> Self {
+        ApproxOptions {
+            significant: 2,
+            rounding: Rounding::HalfUp,
+            hedge: Hedge::default(),
+            noun: None,
+        }
+    }
+}
+
+/// The word put before an approximate number.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Hedge {
+    /// "1.2 million".
+    #[default]
+    None,
+    /// "about 1.2 million".
+    About,
+    /// "over" when rounding went down, "nearly" when it went up, nothing when exact.
+    Auto,
+    /// "over 3 thousand".
+    Over,
+    /// "nearly 4 billion".
+    Nearly,
+    /// Only the order of magnitude: "a few hundred", "several thousand", "tens of millions".
+    Vague,
+}
+
+/// How `Engine::range` writes a range of counts.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RangeStyle {
+    pub form: RangeForm,
+    /// Spell out the endpoints ("three") rather than using digits ("3").
+    pub words: bool,
+    pub agreement: RangeAgreement,
+}
+
+/// The wording of a closed range.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum RangeForm {
+    /// "1–3 results", or "one to three results" in words.
+    #[default]
+    Dash,
+    /// "between 1 and 3 results".
+    Between,
+}
+
+/// Which count the noun agrees with.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum RangeAgreement {
+    /// The upper bound, or the only bound: "0–1 file", "more than one user".
+    #[default]
+    UpperBound,
+    /// Always plural: "0–1 files".
+    Plural,
+}
+
+/// How `Engine::date_to_words` writes a date.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DateStyle {
+    /// "October 18th, 2026".
+    #[default]
+    Us,
+    /// "October eighteenth, twenty twenty-six".
+    UsSpelled,
+    /// "18 October 2026".
+    Uk,
+    /// "the eighteenth of October, twenty twenty-six".
+    UkSpelled,
+}
+
+/// How `Engine::time_to_words` reads a clock time.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TimeStyle {
+    /// "ten forty-five", "three oh-five", "eleven o'clock".
+    #[default]
+    Digital,
+    /// "a quarter to eleven", "half past ten", "five past three".
+    Traditional,
+    /// "fourteen hundred hours", "oh-nine thirty hours".
+    Military,
+}
+
+/// A currency's unit names. Plurals come from `Engine::plural_noun` unless given here.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Currency {
+    /// The ISO 4217 code, such as "USD".
+    pub code: String,
+    pub major: String,
+    pub major_plural: Option<String>,
+    /// The minor unit, or None for currencies such as the yen that have none in use.
+    pub minor: Option<String>,
+    pub minor_plural: Option<String>,
+    /// How many decimal places the minor unit takes (2 for cents).
+    pub minor_digits: usize,
+}
+
+impl Currency {
+    pub fn new(code: &str, major: &str, minor: Option<&str>, minor_digits: usize) -> Currency {
+        Currency {
+            code: code.to_string(),
+            major: major.to_string(),
+            major_plural: None,
+            minor: minor.map(String::from),
+            minor_plural: None,
+            minor_digits: if minor.is_some() { minor_digits } else { 0 },
+        }
+    }
+
+    /// Overrides the plurals produced by the noun pluralizer ("pence", not "pennies").
+    pub fn with_plurals(mut self, major: Option<&str>, minor: Option<&str>) -> Currency {
+        self.major_plural = major.map(String::from);
+        self.minor_plural = minor.map(String::from);
+        self
+    }
+}
+
+/// Currencies known to `Engine::currency`, keyed by code.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CurrencyRegistry {
+    currencies: HashMap<String, Currency>,
+}
+
+impl CurrencyRegistry {
+    /// An empty registry.
+    pub fn new() -> CurrencyRegistry {
+        CurrencyRegistry {
+            currencies: HashMap::new(),
+        }
+    }
+
+    /// Adds a currency, replacing any with the same code.
+    pub fn register(&mut self, currency: Currency) {
+        self.currencies
+            .insert(currency.code.to_uppercase(), currency);
+    }
+
+    pub fn get(&self, code: &str) -> Option<&Currency> {
+        self.currencies.get(&code.to_uppercase())
+    }
+}
+
+impl Default for CurrencyRegistry {
+    fn default() -> Self {
+        let mut registry = CurrencyRegistry::new();
+        for currency in [
+            Currency::new("USD", "dollar", Some("cent"), 2),
+            Currency::new("CAD", "dollar", Some("cent"), 2),
+            Currency::new("AUD", "dollar", Some("cent"), 2),
+            Currency::new("EUR", "euro", Some("cent"), 2),
+            Currency::new("GBP", "pound", Some("penny"), 2).with_plurals(None, Some("pence")),
+            Currency::new("CHF", "franc", Some("centime"), 2),
+            Currency::new("INR", "rupee", Some("paisa"), 2).with_plurals(None, Some("paise")),
+            Currency::new("JPY", "yen", None, 0).with_plurals(Some("yen"), None),
+        ] {
+            registry.register(currency);
+        }
+        registry
+    }
+}
+
+/// How an amount is rounded to the digits a currency or format allows.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Rounding {
+    /// 0.125 -> 0.13.
+    #[default]
+    HalfUp,
+    /// Banker's rounding: 0.125 -> 0.12, 0.135 -> 0.14.
+    HalfEven,
+    /// Truncate: 0.129 -> 0.12.
+    Down,
+}
+
+/// How the minor part of an amount is written.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum MinorStyle {
+    /// "and seven cents".
+    #[default]
+    Words,
+    /// "and 07/100", as on cheques.
+    Fraction,
+}
+
+/// Options for `Engine::currency_to_words`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CurrencyOptions {
+    pub minor_style: MinorStyle,
+    pub rounding: Rounding,
+    /// The word joining the major and minor parts.
+    pub andword: String,
+    /// Used for both numbers. Defaults to no commas: "one thousand two hundred dollars".
+    pub number: NumberToWordsOptions,
+}
+
+impl Default for CurrencyOptions {
+    fn default() -> Self {
+        CurrencyOptions {
+            minor_style: MinorStyle::default(),
+            rounding: Rounding::default(),
+            andword: "and".to_string(),
+            number: NumberToWordsOptions {
+                comma: "".to_string(),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+/// A fraction or mixed number, such as "3/4" or "-2 1/2".
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Fraction {
+    pub negative: bool,
+    pub whole: u64,
+    pub numerator: u64,
+    pub denominator: u64,
+}
+
+impl Fraction {
+    /// A simple fraction; the sign may be on either part.
+    pub fn new(numerator: i64, denominator: i64) -> Fraction {
+        Fraction {
+            negative: (numerator < 0) != (denominator < 0) && numerator != 0,
+            whole: 0,
+            numerator: numerator.unsigned_abs(),
+            denominator: denominator.unsigned_abs(),
+        }
+    }
+}
+
+impl std::str::FromStr for Fraction {
+    type Err = InflectError;
+
+    /// Reads "3/4", "2 1/2", "-2 1/2" or a whole number such as "5".
+    fn from_str(text: &str) -> Result<Fraction, InflectError> {
+        let bad = || InflectError::BadNumValue(text.to_string());
+        let trimmed = text.trim();
+        let (negative, unsigned) = match trimmed.strip_prefix('-') {
+            Some(rest) => (true, rest.trim_start()),
+            None => (false, trimmed),
+        };
+        // The whole part is a separate token before the "n/d" one; "7 / 8" has none.
+        let (whole, numerator, denominator) = match unsigned.split_once('/') {
+            Some((left, d)) => {
+                let (whole, n) = match left.trim().rsplit_once(char::is_whitespace) {
+                    Some((whole, n)) => (whole.trim_end().parse().map_err(|_| bad())?, n),
+                    None => (0, left.trim()),
+                };
+                let n = n.parse().map_err(|_| bad())?;
+                let d = d.trim().parse().map_err(|_| bad())?;
+                (whole, n, d)
+            }
+            None => (unsigned.parse().map_err(|_| bad())?, 0, 1),
+        };
+        if denominator == 0 {
+            return Err(bad());
+        }
+        Ok(Fraction {
+            negative,
+            whole,
+            numerator,
+            denominator,
+        })
+    }
+}
+
+/// Options for `Engine::fraction_to_words`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FractionToWordsOptions {
+    pub fourths: FourthsStyle,
+}
+
+/// The name of the denominator 4.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum FourthsStyle {
+    /// "three quarters".
+    #[default]
+    Quarters,
+    /// "three fourths".
+    Fourths,
+}
+
+/// Options for `Engine::join`, mirroring the keyword arguments of the Python library.
+#[derive(Debug, Clone, PartialEq)]
+pub struct JoinOptions {
+    /// The separator, or None for "," (";" if a word contains a comma).
+    pub sep: Option<String>,
+    pub sep_spaced: bool,
+    /// The separator before the conjunction, or None to use `sep` (the serial comma).
+    pub final_sep: Option<String>,
+    pub conj: String,
+    pub conj_spaced: bool,
+}
+
+impl Default for JoinOptions {
+    fn default() -> Self {
+        JoinOptions {
+            sep: None,
+            sep_spaced: true,
+            final_sep: None,
+            conj: "and".to_string(),
+            conj_spaced: true,
+        }
+    }
+}
+
+/// A unit of time used by `Engine::duration_to_words`. A year is 365 days.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum TimeUnit {
+    Millisecond,
+    Second,
+    Minute,
+    Hour,
+    Day,
+    Week,
+    Year,
+}
+
+impl TimeUnit {
+    const ALL: [TimeUnit; 7] = [
+        TimeUnit::Year,
+        TimeUnit::Week,
+        TimeUnit::Day,
+        TimeUnit::Hour,
+        TimeUnit::Minute,
+        TimeUnit::Second,
+        TimeUnit::Millisecond,
+    ];
+
+    fn millis(self) -> u128 {
+        match self {
+            TimeUnit::Millisecond => 1,
+            TimeUnit::Second => 1000,
+            TimeUnit::Minute => 60 * 1000,
+            TimeUnit::Hour => 60 * 60 * 1000,
+            TimeUnit::Day => 24 * 60 * 60 * 1000,
+            TimeUnit::Week => 7 * 24 * 60 * 60 * 1000,
+            TimeUnit::Year => 365 * 24 * 60 * 60 * 1000,
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            TimeUnit::Millisecond => "millisecond",
+            TimeUnit::Second => "second",
+            TimeUnit::Minute => "minute",
+            TimeUnit::Hour => "hour",
+            TimeUnit::Day => "day",
+            TimeUnit::Week => "week",
+            TimeUnit::Year => "year",
+        }
+    }
+}
+
+/// Whether a duration is in the future ("in 3 days") or the past ("3 days ago").
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Relative {
+    Future,
+    Past,
+}
+
+/// Options for `Engine::duration_to_words`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DurationOptions {
+    pub largest: TimeUnit,
+    pub smallest: TimeUnit,
+    /// Show at most this many units, rounding what is left into the last one.
+    pub max_parts: Option<usize>,
+    pub rounding: Rounding,
+    /// Spell out the counts ("two hours") rather than using digits ("2 hours").
+    pub words: bool,
+    pub relative: Option<Relative>,
+    /// How the parts are joined. The default has no serial comma.
+    pub join: JoinOptions,
+}
+
+impl Default for DurationOptions {
+    fn default() -> Self {
+        DurationOptions {
+            largest: TimeUnit::Day,
+            smallest: TimeUnit::Second,
+            max_parts: None,
+            rounding: Rounding::HalfUp,
+            words: false,
+            relative: None,
+            join: JoinOptions {
+                final_sep: Some("".to_string()),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+/// Options for `Engine::percent_to_words`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PercentOptions {
+    pub word: PercentWord,
+    /// Read a half, quarter or three quarters of a percent in words: "half a percent".
+    pub fractions: bool,
+    pub number: NumberToWordsOptions,
+}
+
+/// The spelling of "%".
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum PercentWord {
+    /// "percent", as in American English.
+    #[default]
+    Percent,
+    /// "per cent", as in British English.
+    PerCent,
+}
+
+/// Options for `Engine::verbalize_numbers`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct VerbalizeOptions {
+    pub number: NumberToWordsOptions,
+    pub currency: CurrencyOptions,
+    pub year: YearToWordsOptions,
+    pub percent: PercentOptions,
+    /// Read four-digit numbers as years when the words around them look like a date.
+    pub years: bool,
+}
+
+impl Default for VerbalizeOptions {
+    fn default() -> Self {
+        VerbalizeOptions {
+            number: NumberToWordsOptions::default(),
+            currency: CurrencyOptions::default(),
+            year: YearToWordsOptions::default(),
+            percent: PercentOptions::default(),
+            years: true,
+        }
+    }
+}
+
+/// Text with its numbers written as words.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Verbalized {
+    pub text: String,
+    /// One entry per replaced number, in order.
+    pub spans: Vec<VerbalizedSpan>,
+}
+
+/// Where a replaced number was in the source and where its words are in the output,
+/// both as byte ranges.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VerbalizedSpan {
+    pub source: std::ops::Range<usize>,
+    pub output: std::ops::Range<usize>,
+}
+
+// TODO: Pre-compiled REGEX objects, ln1950 @ og inflect
+
+pub struct Words {
+    pub lowered: String,
+    pub split_: Vec<String>,
+    pub first: String,
+    pub last: String,
+}
+
+impl Words {
+    pub fn new(s: &str) -> Words {
+        let split: Vec<String> = s.split_whitespace().map(String::from).collect();
+        Words {
+            lowered: s.to_lowercase(),
+            split_: split.clone(),
+            first: split.get(0).cloned().unwrap_or_else(String::new),
+            last: split.last().cloned().unwrap_or_else(String::new),
+        }
+    }
+}
+
+// FIXME: This is terrible. Placeholder.
+#[derive(Debug, Clone)]
+pub struct Word(String);
+
+impl Word {
+    pub fn new(word: String) -> Result<Self, &'static str> {
+        if word.len() >= 1 {
+            Ok(Word(word))
+        } else {
+            Err("Word must be >= 1 chars long.")
+        }
+    }
+
+    pub fn get(&self) -> &str {
+        &self.0
+    }
+}
+
+#[derive(Clone)]
+pub struct Engine {
+    pub classical_dict: HashMap<String, bool>,
+    pub persistent_count: Option<i32>,
+    pub variant_preference: VariantPreference,
+    /// Whether a count of one written with decimals (1.0, "1.00") takes the singular.
+    /// Off by default, giving "1.0 files".
+    pub decimal_one_is_singular: bool,
+    mill_count: usize,
+    pl_sb_user_defined: Vec<Option<Word>>,
+    pl_v_user_defined: Vec<Option<Word>>,
+    pl_adj_user_defined: Vec<Option<Word>>,
+    si_sb_user_defined: Vec<Option<Word>>,
+    a_a_user_defined: Vec<Option<Word>>,
+    the_gender: String,
+    _number_args: Option<NumberToWordsOptions>,
+    /// Currencies that can be looked up by code with `currency`.
+    pub currencies: CurrencyRegistry,
+}
+
+impl Engine {
+    pub fn new() -> Engine {
+        Engine {
+            classical_dict: def_classical(),
+            persistent_count: None,
+            variant_preference: VariantPreference::default(),
+            decimal_one_is_singular: false,
+            mill_count: 0,
+            pl_sb_user_defined: Vec::new(),
+            pl_v_user_defined: Vec::new(),
+            pl_adj_user_defined: Vec::new(),
+            si_sb_user_defined: Vec::new(),
+            a_a_user_defined: Vec::new(),
+            the_gender: "neuter".to_string(),
+            _number_args: None,
+            currencies: CurrencyRegistry::default(),
+        }
+    }
+
+    fn _number_args(&self) -> Option<&NumberToWordsOptions> {
+        self._number_args.as_ref()
+    }
+
+    fn set_number_args(&mut self, args: Option<NumberToWordsOptions>) {
+        self._number_args = args;
+    }
+
+    fn defnoun(&mut self, singular: &Option<Word>, plural: &Option<Word>) {
+        self.checkpat(singular);
+        self.checkpatplural(plural);
+        self.pl_sb_user_defined
+            .extend(vec![singular.clone(), plural.clone()]);
+        self.si_sb_user_defined
+            .extend(vec![plural.clone(), singular.clone()]);
+    }
+
+    fn defverb(
+        &mut self,
+        singular_1st: &Option<Word>,
+        singular_2nd: &Option<Word>,
+        singular_3rd: &Option<Word>,
+        plural_1st: &Option<Word>,
+        plural_2nd: &Option<Word>,
+        plural_3rd: &Option<Word>,
+    ) {
+        self.checkpat(singular_1st);
+        self.checkpat(singular_2nd);
+        self.checkpat(singular_3rd);
+        self.checkpat(plural_1st);
+        self.checkpat(plural_2nd);
+        self.checkpat(plural_3rd);
+        self.pl_v_user_defined.extend(vec![
+            singular_1st.clone(),
+            singular_2nd.clone(),
+            singular_3rd.clone(),
+            plural_1st.clone(),
+            plural_2nd.clone(),
+            plural_3rd.clone(),
+        ]);
+    }
+
+    fn defadj(&mut self, singular: &Option<Word>, plural: &Option<Word>) {
+        self.checkpat(singular);
+        self.checkpatplural(plural);
+        self.pl_adj_user_defined
+            .extend(vec![singular.clone(), plural.clone()]);
+    }
+
+    fn defa(&mut self, pattern: &Option<Word>) {
+        self.checkpat(pattern);
+        self.a_a_user_defined.extend(vec![
+            pattern.clone(),
+            Some(Word::new(String::from("a")).expect("Failed to make Word")),
+        ]);
+    }
+
+    fn defan(&mut self, pattern: &Option<Word>) {
+        self.checkpat(pattern);
+        self.a_a_user_defined.extend(vec![
+            pattern.clone(),
+            Some(Word::new(String::from("an")).expect("Failed to make Word")),
+        ]);
+    }
+
+    fn checkpat(&self, pattern: &Option<Word>) {
+        if pattern.is_none() {
+            return;
+        }
+
+        if pattern.is_some() {
+            let word = pattern.clone().expect("Failed to unwrap Word");
+            let _re = Regex::new(word.get()).expect("Failed to compile regex");
+        }
+    }
+
+    // TODO: Check for replace pattern.
+    fn checkpatplural(&self, pattern: &Option<Word>) {
+        self.checkpat(pattern);
+    }
+
+    pub fn gender(&mut self, gender: &str) {
+        if singular_pronoun_genders().contains(&String::from(gender)) {
+            self.the_gender = gender.to_string();
+        }
+    }
+
+    pub fn check_gender(&self) -> &String {
+        &self.the_gender
+    }
+
+    pub fn get_count<T: Into<Count>>(&self, count: Option<T>) -> i32 {
+        if count.is_none() {
+            if self.persistent_count.is_some() {
+                return self.persistent_count.unwrap();
+            } else {
+                return 0;
+            }
+        }
+
+        let c = count.unwrap().into();
+        match c {
+            Count::Int(n) => i32::try_from(n).unwrap_or(2),
+            Count::Float(n) => {
+                if n == 1.0 && self.decimal_one_is_singular {
+                    1
+                } else {
+                    2
+                }
+            }
+            Count::Category(PluralCategory::One) => 1,
+            Count::Category(PluralCategory::Zero) => 0,
+            Count::Category(_) => 2,
+            Count::Str(s) => {
+                if pl_count_one().contains(&s)
+                    || (*self.classical_dict.get("zero").unwrap_or(&false)
+                        && pl_count_zero().contains(&s.to_lowercase()))
+                {
+                    return 1;
+                }
+                if s.contains('.') && self.decimal_one_is_singular {
+                    if let Ok(n) = s.trim().parse::<f64>() {
+                        if n == 1.0 {
+                            return 1;
+                        }
+                    }
+                }
+                2
+            }
+        }
+    }
+
+    /// Returns the CLDR plural category of `count` under the English rules: "one" when the
+    /// integer part is 1 and no fraction digits are visible ("1", but not "1.0"), else "other".
+    /// Floats are read as Rust prints them, so `1.0f64` has a visible fraction digit.
+    pub fn plural_category<T: Into<Count>>(&self, count: T) -> PluralCategory {
+        let operands = match count.into() {
+            Count::Category(category) => return category,
+            Count::Int(n) => cldr_operands(&n.to_string()),
+            Count::Float(n) => cldr_operands(&format!("{:?}", n)),
+            Count::Str(s) => match cldr_operands(&s) {
+                Some(operands) => Some(operands),
+                None if self.get_count(Some(s)) == 1 => return PluralCategory::One,
+                None => return PluralCategory::Other,
+            },
+        };
+        match operands {
+            Some((i, 0)) if i == "1" => PluralCategory::One,
+            _ => PluralCategory::Other,
+        }
+    }
+
+    fn classical(&self, key: &str) -> bool {
+        *self.classical_dict.get(key).unwrap_or(&false)
+    }
+
+    /// Returns the plural of a noun, or `text` unchanged if `count` is one.
+    pub fn plural_noun<T: Into<Count>>(&self, text: &str, count: Option<T>) -> String {
+        let (pre, word, post) = partition_word(text);
+        if word.is_empty() {
+            return text.to_string();
+        }
+        let count = self.get_count(count);
+        let plural = self.postprocess(word, &self.plnoun(word, count));
+        format!("{}{}{}", pre, plural, post)
+    }
+
+    /// Returns the plural of a verb ("is" -> "are", "runs" -> "run").
+    pub fn plural_verb<T: Into<Count>>(&self, text: &str, count: Option<T>) -> String {
+        let (pre, word, post) = partition_word(text);
+        if word.is_empty() {
+            return text.to_string();
+        }
+        let count = self.get_count(count);
+        let plural = self.postprocess(word, &self.plverb(word, count));
+        format!("{}{}{}", pre, plural, post)
+    }
+
+    /// Returns the singular of a noun, or None if no singular could be found.
+    pub fn singular_noun<T: Into<Count>>(
+        &self,
+        text: &str,
+        count: Option<T>,
+        gender: Option<&str>,
+    ) -> Option<String> {
+        let (pre, word, post) = partition_word(text);
+        if word.is_empty() {
+            return Some(text.to_string());
+        }
+        let count = self.get_count(count);
+        let singular = self.sinoun(word, count, gender)?;
+        Some(format!(
+            "{}{}{}",
+            pre,
+            self.postprocess(word, &singular),
+            post
+        ))
+    }
+
+    /// Returns every accepted plural of a noun, ordered by `variant_preference`.
+    pub fn plural_noun_variants(&self, text: &str) -> Vec<String> {
+        self.variants(text, false, |e, word| Some(e.plnoun(word, 2)))
+    }
+
+    /// Returns every accepted singular of a noun, ordered by `variant_preference`.
+    pub fn singular_noun_variants(&self, text: &str) -> Vec<String> {
+        // A classical plural ("indices") has no modern singular, so the modern reading
+        // ("indice") is only kept when both readings agree.
+        self.variants(text, true, |e, word| e.sinoun(word, 1, None))
+    }
+
+    /// Returns every accepted plural of a verb, ordered by `variant_preference`.
+    pub fn plural_verb_variants(&self, text: &str) -> Vec<String> {
+        self.variants(text, false, |e, word| Some(e.plverb(word, 2)))
+    }
+
+    /// Joins words into a list: ["a", "b", "c"] -> "a, b, and c". The separator becomes
+    /// ";" when a word already contains a comma.
+    pub fn join<T: AsRef<str>>(&self, words: &[T], opts: &JoinOptions) -> String {
+        let words: Vec<&str> = words.iter().map(|w| w.as_ref()).collect();
+        match words.len() {
+            0 => return String::new(),
+            1 => return words[0].to_string(),
+            _ => {}
+        }
+        let conj = match (opts.conj_spaced, opts.conj.as_str()) {
+            (true, "") => " ".to_string(),
+            (true, conj) => format!(" {} ", conj),
+            (false, conj) => conj.to_string(),
+        };
+        if words.len() == 2 {
+            return format!("{}{}{}", words[0], conj, words[1]);
+        }
+        let sep = opts.sep.clone().unwrap_or_else(|| {
+            if words.iter().any(|w| w.contains(',')) {
+                ";".to_string()
+            } else {
+                ",".to_string()
+            }
+        });
+        let final_sep = format!("{}{}", opts.final_sep.as_ref().unwrap_or(&sep), conj);
+        let sep = if opts.sep_spaced {
+            format!("{} ", sep)
+        } else {
+            sep
+        };
+        let (last, rest) = words.split_last().unwrap_or((&"", &[]));
+        format!("{}{}{}", rest.join(&sep), final_sep, last)
+    }
+
+    /// Runs `inflect` once with modern and once with classical settings, and collects
+    /// every alternative of the form "modern|classical" from both results. With
+    /// `classical_wins`, a classical result that differs from the modern one replaces it.
+    fn variants<F>(&self, text: &str, classical_wins: bool, inflect: F) -> Vec<String>
+    where
+        F: Fn(&Engine, &str) -> Option<String>,
+    {
+        let (pre, word, post) = partition_word(text);
+        if word.is_empty() {
+            return vec![text.to_string()];
+        }
+        let mut modern: Vec<String> = Vec::new();
+        let mut classical: Vec<String> = Vec::new();
+        let mut raws: Vec<Option<String>> = Vec::new();
+        for on in [false, true] {
+            let mut e = self.clone();
+            e.classical_dict.insert("all".to_string(), on);
+            raws.push(inflect(&e, word));
+            if let Some(raw) = &raws[raws.len() - 1] {
+                let options: Vec<&str> = raw.split('|').collect();
+                for (i, option) in options.iter().enumerate() {
+                    let form = format!("{}{}{}", pre, e.postprocess(word, option), post);
+                    if i == 0 && !on {
+                        modern.push(form);
+                    } else {
+                        classical.push(form);
+                    }
+                }
+            }
+        }
+        if classical_wins && raws[1].is_some() && raws[0] != raws[1] {
+            modern.clear();
+        }
+        let ordered = match self.variant_preference {
+            VariantPreference::ModernFirst => modern.into_iter().chain(classical),
+            VariantPreference::ClassicalFirst => classical.into_iter().chain(modern),
+        };
+        let mut out: Vec<String> = Vec::new();
+        for form in ordered {
+            if !out.contains(&form) {
+                out.push(form);
+            }
+        }
+        out
+    }
+
+    /// Picks one of the "modern|classical" alternatives and restores the original capitalisation.
+    /// Classical `ancient` only prefers the archaic forms ("brethren", "kine").
+    fn postprocess(&self, orig: &str, inflected: &str) -> String {
+        let pick = |modern: &str, classical: &str| -> String {
+            if self.classical("all")
+                || (self.classical("ancient") && pl_sb_irregular_archaic().contains(&classical))
+            {
+                classical.to_string()
+            } else {
+                modern.to_string()
+            }
+        };
+        let mut result: Vec<String> = if inflected.contains('|') {
+            let options: Vec<&str> = inflected.split('|').collect();
+            if options[0].split(' ').count() == options[1].split(' ').count() {
+                pick(options[0], options[1])
+                    .split(' ')
+                    .map(String::from)
+                    .collect()
+            } else {
+                inflected
+                    .split(' ')
+                    .map(|w| match w.split_once('|') {
+                        Some((modern, classical)) => pick(modern, classical),
+                        None => w.to_string(),
+                    })
+                    .collect()
+            }
+        } else {
+            inflected.split(' ').map(String::from).collect()
+        };
+        for (i, w) in orig.split(' ').enumerate() {
+            if i >= result.len() || w == "I" {
+                continue;
+            }
+            if capitalize(w) == w {
+                result[i] = capitalize(&result[i]);
+            }
+            if w == w.to_uppercase() {
+                result[i] = result[i].to_uppercase();
+            }
+        }
+        result.join(" ")
+    }
+
+    /// Inflects the first noun that is followed by a preposition in a phrase of three or more words.
+    fn handle_long_compounds(&self, words: &Words, count: i32) -> Option<String> {
+        let split: Vec<&str> = words.split_.iter().map(|s| s.as_str()).collect();
+        let preps = pl_prep_list_da();
+        for i in 0..split.len().saturating_sub(1) {
+            if preps.iter().any(|p| p == split[i + 1]) {
+                let inflected = if count == 1 {
+                    self.sinoun(split[i], 1, None)
+                        .unwrap_or_else(|| split[i].to_string())
+                } else {
+                    self.plnoun(split[i], count)
+                };
+                let mut out: Vec<String> = split[..i].iter().map(|s| s.to_string()).collect();
+                out.push(inflected);
+                out.extend(split[i + 1..].iter().map(|s| s.to_string()));
+                return Some(out.join(" "));
+            }
+        }
+        None
+    }
+
+    /// Inflects the word before the first preposition in a `sep`-separated phrase ("mother-in-law").
+    fn handle_prepositional_phrase<F>(
+        &self,
+        phrase: &str,
+        transform: F,
+        sep: &str,
+    ) -> Option<String>
+    where
+        F: Fn(&str) -> Option<String>,
+    {
+        let parts: Vec<&str> = phrase.split(sep).collect();
+        if parts.len() < 3 {
+            return None;
+        }
+        let pivot = find_pivot(&parts, &pl_prep_list_da())?;
+        let transformed =
+            transform(parts[pivot - 1]).unwrap_or_else(|| parts[pivot - 1].to_string());
+        let mut out: Vec<String> = parts[..pivot - 1].iter().map(|s| s.to_string()).collect();
+        out.push([transformed.as_str(), parts[pivot], ""].join(sep));
+        Some(format!("{}{}", out.join(" "), parts[pivot + 1..].join(" ")))
+    }
+
+    fn plnoun(&self, word: &str, count: i32) -> String {
+        if count == 1 || word.is_empty() {
+            return word.to_string();
+        }
+
+        // HANDLE UNINFLECTED PLURALS
+
+        let words = Words::new(word);
+        let lowered = words.lowered.as_str();
+        let last_lower = words.last.to_lowercase();
+
+        if pl_sb_uninflected_complete().contains(&last_lower) {
+            if words.split_.len() >= 3 {
+                return self
+                    .handle_long_compounds(&words, 2)
+                    .unwrap_or_else(|| word.to_string());
+            }
+            return word.to_string();
+        }
+        if pl_sb_uninflected_caps().iter().any(|w| w == word) {
+            return word.to_string();
+        }
+        if ends_with_bysize(lowered, &pl_sb_uninflected_bysize()) {
+            return word.to_string();
+        }
+        if self.classical("herd") && is_uninflected_herd(lowered) {
+            return word.to_string();
+        }
+
+        // HANDLE COMPOUNDS ("Governor General", "mother-in-law", "aide-de-camp", ETC.)
+
+        if let Some((noun, rest)) = pl_sb_postfix_adj_stems(word) {
+            if !rest.is_empty() {
+                return format!("{}{}", self.plnoun(&noun, 2), rest);
+            }
+        }
+
+        if lowered.contains(" a ") || lowered.contains("-a-") {
+            let re = Regex::new(&format!("(?i){}", pl_sb_prep_dual_compound()))
+                .expect("Failed to compile regex");
+            if let Some(caps) = re.captures(word) {
+                if !caps[2].is_empty() && !caps[3].is_empty() {
+                    return format!(
+                        "{}{}{}",
+                        self.plnoun(&caps[1], 2),
+                        &caps[2],
+                        self.plnoun(&caps[3], 2)
+                    );
+                }
+            }
+        }
+
+        if words.split_.len() >= 3 {
+            if let Some(handled) = self.handle_long_compounds(&words, 2) {
+                return handled;
+            }
+        }
+
+        // Only pluralize denominators in units ("meter per second").
+        let denominator = Regex::new(r"^(.+)( (?:per|a) .+)$").expect("Failed to compile regex");
+        if let Some(caps) = denominator.captures(lowered) {
+            let index = caps[1].len();
+            return format!("{}{}", self.plnoun(&word[..index], 2), &word[index..]);
+        }
+
+        // "degree Celsius" -> "degrees Celsius", but only one word may follow.
+        if words.split_.len() >= 2 && words.split_[words.split_.len() - 2] == "degree" {
+            let mut out = vec![self.plnoun(&words.first, 2)];
+            out.extend(words.split_[1..].iter().cloned());
+            return out.join(" ");
+        }
+
+        if let Some(handled) =
+            self.handle_prepositional_phrase(lowered, |w| Some(self.plnoun(w, 2)), "-")
+        {
+            return handled;
+        }
+
+        // HANDLE PRONOUNS
+
+        for (k, v) in pl_pron_acc_keys_bysize() {
+            if v.contains(tail(lowered, k)) {
+                for (pk, pv) in pl_prep_bysize() {
+                    let head = chop(lowered, lowered.chars().count().saturating_sub(pk));
+                    if pv.contains(head) {
+                        let split: Vec<&str> = lowered.split_whitespace().collect();
+                        if split == [head, tail(lowered, k)] {
+                            let acc = pl_pron_acc();
+                            return format!("{}{}", chop(lowered, k), acc[tail(lowered, k)]);
+                        }
+                    }
+                }
+            }
+        }
+        if let Some(pron) = pl_pron_nom().get(lowered) {
+            return pron.clone();
+        }
+        if let Some(pron) = pl_pron_acc().get(lowered) {
+            return pron.clone();
+        }
+
+        // HANDLE ISOLATED IRREGULAR PLURALS
+
+        let last_len = words.last.chars().count();
+        if let Some(pl) = pl_sb_irregular_caps().get(words.last.as_str()) {
+            return format!("{}{}", chop(word, last_len), pl);
+        }
+        if let Some(pl) = pl_sb_irregular().get(&last_lower) {
+            return format!("{}{}", chop(word, last_len), pl);
+        }
+        let dash_split: Vec<&str> = lowered.split('-').collect();
+        let compound = dash_split[dash_split.len().saturating_sub(2)..].join(" ");
+        if let Some(pl) = pl_sb_irregular_compound().get(compound.as_str()) {
+            return format!("{}{}", chop(word, compound.chars().count()), pl);
+        }
+
+        if lowered.ends_with("quy") {
+            return format!("{}ies", chop(word, 1));
+        }
+        if lowered.ends_with("person") {
+            if self.classical("persons") {
+                return format!("{}s", word);
+            }
+            return format!("{}ople", chop(word, 4));
+        }
+
+        // HANDLE FAMILIES OF IRREGULAR PLURALS
+
+        if lowered.ends_with("man") {
+            if ends_with_bysize(lowered, &pl_sb_u_man_mans_bysize())
+                || ends_with_bysize(word, &pl_sb_u_man_mans_caps_bysize())
+            {
+                return format!("{}s", word);
+            }
+            return format!("{}men", chop(word, 3));
+        }
+        if lowered.ends_with("mouse") {
+            return format!("{}mice", chop(word, 5));
+        }
+        if lowered.ends_with("louse") {
+            if let Some(v) = pl_sb_u_louse_lice_bysize().get(&word.len()) {
+                if v.contains(lowered) {
+                    return format!("{}lice", chop(word, 5));
+                }
+            }
+            return format!("{}s", word);
+        }
+        if lowered.ends_with("goose") {
+            return format!("{}geese", chop(word, 5));
+        }
+        if lowered.ends_with("tooth") {
+            return format!("{}teeth", chop(word, 5));
+        }
+        if lowered.ends_with("foot") {
+            return format!("{}feet", chop(word, 4));
+        }
+        if lowered == "die" {
+            return "dice".to_string();
+        }
+
+        // HANDLE UNASSIMILATED IMPORTS
+
+        if lowered.ends_with("ceps") {
+            return word.to_string();
+        }
+        if lowered.ends_with("zoon") {
+            return format!("{}a", chop(word, 2));
+        }
+        if ["cis", "sis", "xis"].contains(&tail(lowered, 3)) {
+            return format!("{}es", chop(word, 2));
+        }
+
+        for (lastlet, table, numend, post) in [
+            ('h', pl_sb_u_ch_chs_bysize(), 0, "s"),
+            ('x', pl_sb_u_ex_ices_bysize(), 2, "ices"),
+            ('x', pl_sb_u_ix_ices_bysize(), 2, "ices"),
+            ('m', pl_sb_u_um_a_bysize(), 2, "a"),
+            ('s', pl_sb_u_us_i_bysize(), 2, "i"),
+            ('n', pl_sb_u_on_a_bysize(), 2, "a"),
+            ('a', pl_sb_u_a_ae_bysize(), 0, "e"),
+        ] {
+            if lowered.ends_with(lastlet) && ends_with_bysize(lowered, &table) {
+                return format!("{}{}", chop(word, numend), post);
+            }
+        }
+
+        // HANDLE INCOMPLETELY ASSIMILATED IMPORTS
+
+        if self.classical("all") {
+            if lowered.ends_with("trix") {
+                return format!("{}ces", chop(word, 1));
+            }
+            if lowered.ends_with("eau") || lowered.ends_with("ieu") {
+                return format!("{}x", word);
+            }
+            if ["ynx", "inx", "anx"].contains(&tail(lowered, 3)) && word.chars().count() > 4 {
+                return format!("{}ges", chop(word, 1));
+            }
+
+            for (lastlet, table, numend, post) in [
+                ('n', pl_sb_c_en_ina_bysize(), 2, "ina"),
+                ('x', pl_sb_c_ex_ices_bysize(), 2, "ices"),
+                ('x', pl_sb_c_ix_ices_bysize(), 2, "ices"),
+                ('m', pl_sb_c_um_a_bysize(), 2, "a"),
+                ('s', pl_sb_c_us_i_bysize(), 2, "i"),
+                ('s', pl_sb_c_us_us_bysize(), 0, ""),
+                ('a', pl_sb_c_a_ae_bysize(), 0, "e"),
+                ('a', pl_sb_c_a_ata_bysize(), 0, "ta"),
+                ('s', pl_sb_c_is_ides_bysize(), 1, "des"),
+                ('o', pl_sb_c_o_i_bysize(), 1, "i"),
+                ('n', pl_sb_c_on_a_bysize(), 2, "a"),
+            ] {
+                if lowered.ends_with(lastlet) && ends_with_bysize(lowered, &table) {
+                    return format!("{}{}", chop(word, numend), post);
+                }
+            }
+
+            for (table, post) in [(pl_sb_c_i_bysize(), "i"), (pl_sb_c_im_bysize(), "im")] {
+                if ends_with_bysize(lowered, &table) {
+                    return format!("{}{}", word, post);
+                }
+            }
+        }
+
+        // HANDLE SINGULAR NOUNS ENDING IN ...s OR OTHER SILIBANTS
+
+        if pl_sb_singular_s_complete().contains(&last_lower)
+            || ends_with_bysize(lowered, &pl_sb_singular_s_bysize())
+        {
+            return format!("{}es", word);
+        }
+
+        // HANDLE PROPER NAMES ("Jones" -> "Joneses", "Sims" -> "Simses")
+
+        if self.classical("names") && starts_upper(word) && lowered.ends_with('s') {
+            return format!("{}es", word);
+        }
+
+        if lowered.ends_with('z') {
+            if ends_with_bysize(lowered, &pl_sb_z_zes_bysize()) {
+                return format!("{}es", word);
+            }
+            if !chop(lowered, 1).ends_with('z') {
+                return format!("{}zes", word);
+            }
+        }
+        if lowered.ends_with("ze") && ends_with_bysize(lowered, &sb_ze_zes_bysize()) {
+            return format!("{}s", word);
+        }
+        if ["ch", "sh", "zz", "ss"].contains(&tail(lowered, 2)) || lowered.ends_with('x') {
+            return format!("{}es", word);
+        }
+
+        // HANDLE ...f -> ...ves
+
+        if ["elf", "alf", "olf", "arf"].contains(&tail(lowered, 3)) {
+            return format!("{}ves", chop(word, 1));
+        }
+        if lowered.ends_with("eaf") && !chop(lowered, 3).ends_with('d') {
+            return format!("{}ves", chop(word, 1));
+        }
+        if ["nife", "life", "wife"].contains(&tail(lowered, 4)) {
+            return format!("{}ves", chop(word, 2));
+        }
+
+        // HANDLE ...y
+
+        if lowered.ends_with('y') {
+            if chop(lowered, 1).ends_with(['a', 'e', 'i', 'o', 'u']) || word.chars().count() == 1 {
+                return format!("{}s", word);
+            }
+            if self.classical("names") && starts_upper(word) {
+                return format!("{}s", word);
+            }
+            return format!("{}ies", chop(word, 1));
+        }
+
+        // HANDLE ...o
+
+        if pl_sb_u_o_os_complete()
+            .iter()
+            .any(|w| w.to_lowercase() == last_lower)
+            || ends_with_bysize(lowered, &pl_sb_u_o_os_bysize())
+            || ["ao", "eo", "io", "oo", "uo"].contains(&tail(lowered, 2))
+        {
+            return format!("{}s", word);
+        }
+        if lowered.ends_with('o') {
+            return format!("{}es", word);
+        }
+
+        // OTHERWISE JUST ADD ...s
+
+        format!("{}s", word)
+    }
+
+    fn sinoun(&self, word: &str, count: i32, gender: Option<&str>) -> Option<String> {
+        if count == 2 || word.is_empty() {
+            return Some(word.to_string());
+        }
+        let gender = gender.unwrap_or(&self.the_gender);
+
+        if si_sb_ois_oi_case().iter().any(|w| w == word) {
+            return Some(chop(word, 1).to_string());
+        }
+
+        // HANDLE UNINFLECTED PLURALS
+
+        let words = Words::new(word);
+        let lowered = words.lowered.as_str();
+        let last_lower = words.last.to_lowercase();
+
+        if pl_sb_uninflected_complete().contains(&last_lower) {
+            if words.split_.len() >= 3 {
+                return self
+                    .handle_long_compounds(&words, 1)
+                    .or_else(|| Some(word.to_string()));
+            }
+            return Some(word.to_string());
+        }
+        if pl_sb_uninflected_caps().iter().any(|w| w == word)
+            || ends_with_bysize(lowered, &pl_sb_uninflected_bysize())
+        {
+            return Some(word.to_string());
+        }
+        if self.classical("herd") && is_uninflected_herd(lowered) {
+            return Some(word.to_string());
+        }
+        if pl_sb_c_us_us().contains(&last_lower) {
+            return if self.classical("all") {
+                Some(word.to_string())
+            } else {
+                None
+            };
+        }
+
+        // HANDLE COMPOUNDS ("Governors General", "mothers-in-law", "aides-de-camp", ETC.)
+
+        if let Some((noun, rest)) = pl_sb_postfix_adj_stems(word) {
+            if !rest.is_empty() {
+                let singular = self.sinoun(&noun, 1, Some(gender)).unwrap_or(noun);
+                return Some(format!("{}{}", singular, rest));
+            }
+        }
+        for sep in [" ", "-"] {
+            if let Some(handled) =
+                self.handle_prepositional_phrase(lowered, |w| self.sinoun(w, 1, Some(gender)), sep)
+            {
+                return Some(handled);
+            }
+        }
+
+        // HANDLE PRONOUNS
+
+        let split: Vec<&str> = lowered.split_whitespace().collect();
+        if split.len() == 2 && pl_prep_list_da().iter().any(|p| p == split[0]) {
+            if let Some(sing) = si_pron_lookup("acc", split[1], gender) {
+                return Some(format!("{}{}", chop(lowered, split[1].len()), sing));
+            }
+        }
+        for case in ["nom", "acc"] {
+            if let Some(sing) = si_pron_lookup(case, lowered, gender) {
+                return Some(if sing == "i" { "I".to_string() } else { sing });
+            }
+        }
+
+        // HANDLE ISOLATED IRREGULAR PLURALS
+
+        let last_len = words.last.chars().count();
+        if let Some(si) = si_sb_irregular_caps().get(words.last.as_str()) {
+            return Some(format!("{}{}", chop(word, last_len), si));
+        }
+        if let Some(si) = si_sb_irregular().get(&last_lower) {
+            return Some(format!("{}{}", chop(word, last_len), si));
+        }
+        let dash_split: Vec<&str> = lowered.split('-').collect();
+        let compound = dash_split[dash_split.len().saturating_sub(2)..].join(" ");
+        if let Some(si) = si_sb_irregular_compound().get(compound.as_str()) {
+            return Some(format!("{}{}", chop(word, compound.chars().count()), si));
+        }
+
+        if lowered.ends_with("quies") {
+            return Some(format!("{}y", chop(word, 3)));
+        }
+        if lowered.ends_with("persons") {
+            return Some(chop(word, 1).to_string());
+        }
+        if lowered.ends_with("people") {
+            return Some(format!("{}rson", chop(word, 4)));
+        }
+
+        // HANDLE FAMILIES OF IRREGULAR PLURALS
+
+        if lowered.ends_with("mans")
+            && (ends_with_bysize(lowered, &si_sb_u_man_mans_bysize())
+                || ends_with_bysize(word, &si_sb_u_man_mans_caps_bysize()))
+        {
+            return Some(chop(word, 1).to_string());
+        }
+        if lowered.ends_with("men") {
+            return Some(format!("{}man", chop(word, 3)));
+        }
+        if lowered.ends_with("mice") {
+            return Some(format!("{}mouse", chop(word, 4)));
+        }
+        if lowered.ends_with("lice") {
+            if let Some(v) = si_sb_u_louse_lice_bysize().get(&word.len()) {
+                if v.contains(lowered) {
+                    return Some(format!("{}louse", chop(word, 4)));
+                }
+            }
+        }
+        if lowered.ends_with("geese") {
+            return Some(format!("{}goose", chop(word, 5)));
+        }
+        if lowered.ends_with("teeth") {
+            return Some(format!("{}tooth", chop(word, 5)));
+        }
+        if lowered.ends_with("feet") {
+            return Some(format!("{}foot", chop(word, 4)));
+        }
+        if lowered == "dice" {
+            return Some("die".to_string());
+        }
+
+        // HANDLE UNASSIMILATED IMPORTS
+
+        if lowered.ends_with("ceps") {
+            return Some(word.to_string());
+        }
+        if lowered.ends_with("zoa") {
+            return Some(format!("{}on", chop(word, 1)));
+        }
+
+        for (lastlet, table, numend, post) in [
+            ('s', si_sb_u_ch_chs_bysize(), 1, ""),
+            ('s', si_sb_u_ex_ices_bysize(), 4, "ex"),
+            ('s', si_sb_u_ix_ices_bysize(), 4, "ix"),
+            ('a', si_sb_u_um_a_bysize(), 1, "um"),
+            ('i', si_sb_u_us_i_bysize(), 1, "us"),
+            ('a', si_sb_u_on_a_bysize(), 1, "on"),
+            ('e', si_sb_u_a_ae_bysize(), 1, ""),
+        ] {
+            if lowered.ends_with(lastlet) && ends_with_bysize(lowered, &table) {
+                return Some(format!("{}{}", chop(word, numend), post));
+            }
+        }
+
+        // HANDLE INCOMPLETELY ASSIMILATED IMPORTS
+
+        if self.classical("all") {
+            if lowered.ends_with("trices") {
+                return Some(format!("{}x", chop(word, 3)));
+            }
+            if lowered.ends_with("eaux") || lowered.ends_with("ieux") {
+                return Some(chop(word, 1).to_string());
+            }
+            if ["ynges", "inges", "anges"].contains(&tail(lowered, 5)) && word.chars().count() > 6 {
+                return Some(format!("{}x", chop(word, 3)));
+            }
+
+            for (lastlet, table, numend, post) in [
+                ('a', si_sb_c_en_ina_bysize(), 3, "en"),
+                ('s', si_sb_c_ex_ices_bysize(), 4, "ex"),
+                ('s', si_sb_c_ix_ices_bysize(), 4, "ix"),
+                ('a', si_sb_c_um_a_bysize(), 1, "um"),
+                ('i', si_sb_c_us_i_bysize(), 1, "us"),
+                ('s', pl_sb_c_us_us_bysize(), 0, ""),
+                ('e', si_sb_c_a_ae_bysize(), 1, ""),
+                ('a', si_sb_c_a_ata_bysize(), 2, ""),
+                ('s', si_sb_c_is_ides_bysize(), 3, "s"),
+                ('i', si_sb_c_o_i_bysize(), 1, "o"),
+                ('a', si_sb_c_on_a_bysize(), 1, "on"),
+                ('m', si_sb_c_im_bysize(), 2, ""),
+                ('i', si_sb_c_i_bysize(), 1, ""),
+            ] {
+                if lowered.ends_with(lastlet) && ends_with_bysize(lowered, &table) {
+                    return Some(format!("{}{}", chop(word, numend), post));
+                }
+            }
+        }
+
+        // HANDLE PLURALS ENDING IN uses -> use, ies -> ie, oes -> oe
+
+        let in_case = |table: Vec<String>| table.iter().any(|w| w == word);
+        if lowered.ends_with("houses")
+            || in_case(si_sb_uses_use_case())
+            || si_sb_use_uses().contains(&last_lower)
+            || in_case(si_sb_ies_ie_case())
+            || si_sb_ies_ie().contains(&last_lower)
+            || lowered.ends_with("shoes")
+            || in_case(si_sb_oes_oe_case())
+            || si_sb_oes_oe().contains(&last_lower)
+        {
+            return Some(chop(word, 1).to_string());
+        }
+
+        // HANDLE SINGULAR NOUNS ENDING IN ...s OR OTHER SILIBANTS
+
+        if in_case(si_sb_sses_sse_case()) || si_sb_sses_sse().contains(&last_lower) {
+            return Some(chop(word, 1).to_string());
+        }
+        if si_sb_singular_s_complete().contains(&last_lower)
+            || ends_with_bysize(lowered, &si_sb_singular_s_bysize())
+        {
+            return Some(chop(word, 2).to_string());
+        }
+        if self.classical("names") && lowered.ends_with("eses") && starts_upper(word) {
+            return Some(chop(word, 2).to_string());
+        }
+        if self.classical("names")
+            && starts_upper(word)
+            && lowered.ends_with("ses")
+            && matches!(
+                lowered.chars().rev().nth(3),
+                Some('b' | 'd' | 'g' | 'k' | 'm' | 't')
+            )
+        {
+            return Some(chop(word, 2).to_string());
+        }
+        if si_sb_z_zes().contains(&last_lower) || si_sb_zzes_zz().contains(&last_lower) {
+            return Some(chop(word, 2).to_string());
+        }
+        if lowered.ends_with("zzes") {
+            return Some(chop(word, 3).to_string());
+        }
+        if in_case(si_sb_ches_che_case()) || si_sb_ches_che().contains(&last_lower) {
+            return Some(chop(word, 1).to_string());
+        }
+        if lowered.ends_with("ches") || lowered.ends_with("shes") {
+            return Some(chop(word, 2).to_string());
+        }
+        if si_sb_xes_xe().contains(&last_lower) {
+            return Some(chop(word, 1).to_string());
+        }
+        if lowered.ends_with("xes") {
+            return Some(chop(word, 2).to_string());
+        }
+
+        // HANDLE ...f -> ...ves
+
+        if in_case(si_sb_ves_ve_case()) || si_sb_ves_ve().contains(&last_lower) {
+            return Some(chop(word, 1).to_string());
+        }
+        if lowered.ends_with("ves") {
+            let stem = tail(chop(lowered, 3), 2);
+            if ["el", "al", "ol", "ar"].contains(&stem)
+                || (stem == "ea" && !chop(lowered, 5).ends_with('d'))
+            {
+                return Some(format!("{}f", chop(word, 3)));
+            }
+            if ["ni", "li", "wi"].contains(&stem) {
+                return Some(format!("{}fe", chop(word, 3)));
+            }
+        }
+
+        // HANDLE ...y
+
+        if lowered.ends_with("ys") {
+            if chop(lowered, 2).ends_with(['a', 'e', 'i', 'o', 'u']) {
+                return Some(chop(word, 1).to_string());
+            }
+            if self.classical("names") && starts_upper(word) {
+                return Some(chop(word, 1).to_string());
+            }
+        }
+        if lowered.ends_with("ies") {
+            return Some(format!("{}y", chop(word, 3)));
+        }
+
+        // HANDLE ...o
+
+        if lowered.ends_with("os")
+            && (si_sb_u_o_os_complete()
+                .iter()
+                .any(|w| w.to_lowercase() == last_lower)
+                || ends_with_bysize(lowered, &si_sb_u_o_os_bysize())
+                || ["aos", "eos", "ios", "oos", "uos"].contains(&tail(lowered, 3)))
+        {
+            return Some(chop(word, 1).to_string());
+        }
+        if lowered.ends_with("oes") {
+            return Some(chop(word, 2).to_string());
+        }
+
+        // UNASSIMILATED IMPORTS FINAL RULE
+
+        if si_sb_es_is().iter().any(|w| w == word) {
+            return Some(format!("{}is", chop(word, 2)));
+        }
+
+        // OTHERWISE JUST REMOVE ...s
+
+        if lowered.ends_with('s') {
+            return Some(chop(word, 1).to_string());
+        }
+
+        // COULD NOT FIND SINGULAR
+
+        None
+    }
+
+    fn plverb(&self, word: &str, count: i32) -> String {
+        self.pl_special_verb(word, count)
+            .unwrap_or_else(|| self.pl_general_verb(word, count))
+    }
+
+    fn pl_special_verb(&self, word: &str, count: i32) -> Option<String> {
+        if count == 1 {
+            return Some(word.to_string());
+        }
+        if word.is_empty() {
+            return None;
+        }
+
+        // HANDLE IRREGULAR PRESENT TENSE (SIMPLE AND COMPOUND)
+
+        let words = Words::new(word);
+        let rest = skip(word, words.first.chars().count());
+        let irregular = plverb_irregular_pres();
+        if let Some(pl) = irregular.get(&words.first) {
+            return Some(format!("{}{}", pl, rest));
+        }
+
+        // HANDLE IRREGULAR FUTURE, PRETERITE AND PERFECT TENSES
+
+        if plverb_irregular_non_pres().contains(&words.first) {
+            return Some(word.to_string());
+        }
+
+        // HANDLE PRESENT NEGATIONS (SIMPLE AND COMPOUND)
+
+        if let Some(stem) = words.first.strip_suffix("n't") {
+            if let Some(pl) = irregular.get(stem) {
+                return Some(format!("{}n't{}", pl, rest));
+            }
+            return Some(word.to_string());
+        }
+
+        // HANDLE SPECIAL CASES
+
+        let special =
+            Regex::new(&format!("^{}$", plverb_special_s())).expect("Failed to compile regex");
+        if special.is_match(word) || word.contains(char::is_whitespace) {
+            return None;
+        }
+        if words.lowered == "quizzes" {
+            return Some("quiz".to_string());
+        }
+
+        // HANDLE STANDARD 3RD PERSON (CHOP THE ...(e)s OFF SINGLE WORDS)
+
+        let lowered = words.lowered.as_str();
+        if ["ches", "shes", "zzes", "sses"].contains(&tail(lowered, 4)) || lowered.ends_with("xes")
+        {
+            return Some(chop(word, 2).to_string());
+        }
+        if lowered.ends_with("ies") && word.chars().count() > 3 {
+            return Some(format!("{}y", chop(lowered, 3)));
+        }
+        if pl_v_oes_oe().contains(&words.last.to_lowercase())
+            || pl_v_oes_oe_endings_size4()
+                .iter()
+                .any(|w| w == tail(lowered, 4))
+            || pl_v_oes_oe_endings_size5()
+                .iter()
+                .any(|w| w == tail(lowered, 5))
+        {
+            return Some(chop(word, 1).to_string());
+        }
+        if lowered.ends_with("oes") && word.chars().count() > 3 {
+            return Some(chop(lowered, 2).to_string());
+        }
+        if lowered.ends_with('s') && !lowered.ends_with("ss") && word.chars().count() > 1 {
+            return Some(chop(word, 1).to_string());
+        }
+
+        // OTHERWISE, A REGULAR VERB (HANDLE ELSEWHERE)
+
+        None
+    }
+
+    fn pl_general_verb(&self, word: &str, count: i32) -> String {
+        if count == 1 {
+            return word.to_string();
+        }
+
+        // HANDLE AMBIGUOUS PRESENT TENSES (SIMPLE AND COMPOUND)
+
+        if let Some(caps) = plverb_ambiguous_pres_keys().captures(word) {
+            let ambiguous = plverb_ambiguous_pres();
+            return format!("{}{}", ambiguous[&caps[1].to_lowercase()], &caps[2]);
+        }
+
+        // HANDLE AMBIGUOUS PRETERITE AND PERFECT TENSES
+
+        if let Some(caps) = plverb_ambiguous_non_pres().captures(word) {
+            return format!("{}{}", &caps[1], &caps[2]);
+        }
+
+        // OTHERWISE, 1st OR 2ND PERSON IS UNINFLECTED
+
+        word.to_string()
+    }
+
+    /// The options of the `number_to_words` call in progress.
+    fn number_args(&self) -> &NumberToWordsOptions {
+        self._number_args()
+            .expect("number args are set by number_to_words")
+    }
+
+    /// Names the `ind`th digit group in the current numbering system. Past the last name,
+    /// the largest name is repeated if the options allow it ("one thousand centillion").
+    fn millfn(&self, ind: usize) -> Result<String, InflectError> {
+        let mill = self.number_args().numbering.scale_names();
+        if let Some(name) = mill.get(ind) {
+            return Ok(name.clone());
+        }
+        match self.number_args().scale_overflow {
+            ScaleOverflow::Error => Err(InflectError::NumOutOfRange),
+            ScaleOverflow::Compound => {
+                let largest = mill.len() - 1;
+                Ok(format!("{}{}", self.millfn(ind - largest)?, mill[largest]))
+            }
+        }
+    }
+
+    fn unitfn(&self, units: usize, mindex: usize) -> Result<String, InflectError> {
+        Ok(format!("{}{}", unit()[units], self.millfn(mindex)?))
+    }
+
+    fn tenfn(&self, tens: usize, units: usize, mindex: usize) -> Result<String, InflectError> {
+        if tens != 1 {
+            let hyphen = if tens != 0 && units != 0 { "-" } else { "" };
+            return Ok(format!(
+                "{}{}{}{}",
+                ten()[tens],
+                hyphen,
+                unit()[units],
+                self.millfn(mindex)?
+            ));
+        }
+        Ok(format!("{}{}", teen()[units], self.millfn(mindex)?))
+    }
+
+    fn hundfn(
+        &self,
+        hundreds: usize,
+        tens: usize,
+        units: usize,
+        mindex: usize,
+    ) -> Result<String, InflectError> {
+        if hundreds != 0 {
+            let andword = if tens != 0 || units != 0 {
+                format!(" {} ", self.number_args().andword.clone())
+            } else {
+                String::new()
+            };
+            return Ok(format!(
+                "{} hundred{}{}{}, ",
+                unit()[hundreds],
+                andword,
+                self.tenfn(tens, units, 0)?,
+                self.millfn(mindex)?
+            ));
+        }
+        if tens != 0 || units != 0 {
+            return Ok(format!(
+                "{}{}, ",
+                self.tenfn(tens, units, 0)?,
+                self.millfn(mindex)?
+            ));
+        }
+        Ok(String::new())
+    }
+
+    fn group1sub(&self, units: usize) -> String {
+        match units {
+            1 => format!(" {}, ", self.number_args().one.clone()),
+            0 => format!(" {}, ", self.number_args().zero.clone()),
+            _ => format!("{}, ", unit()[units]),
+        }
+    }
+
+    fn group1bsub(&self, units: usize) -> String {
+        match units {
+            0 => format!(" {}, ", self.number_args().zero.clone()),
+            _ => format!("{}, ", unit()[units]),
+        }
+    }
+
+    fn group2sub(&self, tens: usize, units: usize) -> Result<String, InflectError> {
+        let zero = self.number_args().zero.clone();
+        if tens != 0 {
+            return Ok(format!("{}, ", self.tenfn(tens, units, 0)?));
+        }
+        if units != 0 {
+            return Ok(format!(" {} {}, ", zero, unit()[units]));
+        }
+        Ok(format!(" {} {}, ", zero, zero))
+    }
+
+    fn group3sub(
+        &self,
+        hundreds: usize,
+        tens: usize,
+        units: usize,
+    ) -> Result<String, InflectError> {
+        let zero = self.number_args().zero.clone();
+        let hunword = match hundreds {
+            1 => format!(" {}", self.number_args().one.clone()),
+            0 => format!(" {}", zero),
+            _ => unit()[hundreds].clone(),
+        };
+        let tenword = if tens != 0 {
+            self.tenfn(tens, units, 0)?
+        } else if units != 0 {
+            format!(" {} {}", zero, unit()[units])
+        } else {
+            format!(" {} {}", zero, zero)
+        };
+        Ok(format!("{} {}, ", hunword, tenword))
+    }
+
+    /// Converts a string of digits to words, either whole or in groups of `group` digits.
+    fn enword(&mut self, num: &str, group: usize) -> Result<String, InflectError> {
+        let digits: Vec<usize> = num
+            .chars()
+            .filter_map(|c| c.to_digit(10))
+            .map(|d| d as usize)
+            .collect();
+        let mut out = String::new();
+        match group {
+            1 => {
+                for d in digits {
+                    out.push_str(&self.group1sub(d));
+                }
+            }
+            2 => {
+                let pairs = digits.chunks_exact(2);
+                let rest = pairs.remainder().to_vec();
+                for pair in pairs {
+                    out.push_str(&self.group2sub(pair[0], pair[1])?);
+                }
+                if let Some(&d) = rest.first() {
+                    out.push_str(&self.group1bsub(d));
+                }
+            }
+            3 => {
+                let triples = digits.chunks_exact(3);
+                let rest = triples.remainder().to_vec();
+                for triple in triples {
+                    out.push_str(&self.group3sub(triple[0], triple[1], triple[2])?);
+                }
+                match rest.len() {
+                    2 => out.push_str(&self.group2sub(rest[0], rest[1])?),
+                    1 => out.push_str(&self.group1sub(rest[0])),
+                    _ => {}
+                }
+            }
+            _ => {
+                let significant: Vec<usize> = digits.into_iter().skip_while(|&d| d == 0).collect();
+                if significant.is_empty() {
+                    return Ok(self.number_args().zero.clone());
+                }
+                if significant == [1] {
+                    return Ok(self.number_args().one.clone());
+                }
+                out = self.scale_groups(&significant)?;
+            }
+        }
+        Ok(out)
+    }
+
+    /// Names each digit group from the right, then whatever is left over. Indian numbering
+    /// groups by three, then by two up to crore; anything above a crore is a count of crores.
+    fn scale_groups(&mut self, digits: &[usize]) -> Result<String, InflectError> {
+        let indian = self.number_args().numbering == NumberingSystem::Indian;
+        self.mill_count = 0;
+        let mut groups: Vec<String> = Vec::new();
+        let mut rest = digits;
+        loop {
+            if indian && self.mill_count == 3 && !rest.is_empty() {
+                let crores = self.scale_groups(rest)?;
+                let crores = crores.strip_suffix(", ").unwrap_or(&crores);
+                groups.push(format!("{}{}, ", crores, self.millfn(3)?));
+                rest = &[];
+                break;
+            }
+            let size = if indian && self.mill_count > 0 { 2 } else { 3 };
+            if rest.len() < size {
+                break;
+            }
+            let (head, last) = rest.split_at(rest.len() - size);
+            let group = match last {
+                [t, u] => self.hundfn(0, *t, *u, self.mill_count)?,
+                _ => self.hundfn(last[0], last[1], last[2], self.mill_count)?,
+            };
+            groups.push(group);
+            self.mill_count += 1;
+            rest = head;
+        }
+        match rest.len() {
+            2 => groups.push(format!(
+                "{}, ",
+                self.tenfn(rest[0], rest[1], self.mill_count)?
+            )),
+            1 => groups.push(format!("{}, ", self.unitfn(rest[0], self.mill_count)?)),
+            _ => {}
+        }
+        groups.reverse();
+        Ok(groups.concat())
+    }
+
+    /// Replaces the last word of a cardinal with its ordinal ("twenty-one" -> "twenty-first").
+    fn sub_ord(&self, val: &str) -> String {
+        let ordinals = ordinal();
+        let new = ordinal_suff()
+            .replace(val, |caps: &regex::Captures| ordinals[&caps[1]].clone())
+            .to_string();
+        if new == val {
+            format!("{}th", new)
+        } else {
+            new
+        }
+    }
+
+    /// Returns the ordinal of a number: "21" -> "21st", "twenty-one" -> "twenty-first".
+    pub fn ordinal<T: ToString>(&self, num: T) -> String {
+        let num = num.to_string();
+        if !num.starts_with(|c: char| c.is_ascii_digit()) {
+            return self.sub_ord(&num);
+        }
+        // Only the last two digits decide the suffix; for decimals that is "1.1st".
+        let digits: String = num.chars().filter(|c| c.is_ascii_digit()).collect();
+        format!(
+            "{}{}",
+            num,
+            nth_suffix(tail(&digits, 2).parse().unwrap_or(0))
+        )
+    }
+
+    /// Spells out an ordinal: 1000000 -> "one millionth", 2000000001 -> "two billion and
+    /// first". Negative numbers are read with "minus" ("minus third") and a "+" is dropped.
+    /// Decimals have no ordinal and are an error.
+    pub fn ordinal_words<T: ToString>(&mut self, n: T) -> Result<String, InflectError> {
+        let n = n.to_string();
+        let trimmed = n.trim();
+        let (sign, digits) = match trimmed.strip_prefix('-') {
+            Some(rest) => ("-", rest),
+            None => ("", trimmed.strip_prefix('+').unwrap_or(trimmed)),
+        };
+        if !digits.starts_with(|c: char| c.is_ascii_digit())
+            || digits.contains('.')
+            || !is_number_text(digits)
+        {
+            return Err(InflectError::BadNumValue(n));
+        }
+        let words = self.number_to_words(
+            format!("{}{}", sign, digits.replace(',', "")),
+            &NumberToWordsOptions::default(),
+        )?;
+        Ok(self.sub_ord(&words))
+    }
+
+    /// How many times something happened: "once", "twice", "three times". Gives "thrice"
+    /// when `classical(ancient)` is on.
+    pub fn times(&mut self, n: u64) -> Result<String, InflectError> {
+        Ok(match n {
+            1 => "once".to_string(),
+            2 => "twice".to_string(),
+            3 if self.classical("ancient") => "thrice".to_string(),
+            _ => format!(
+                "{} times",
+                self.number_to_words(n, &NumberToWordsOptions::default())?
+            ),
+        })
+    }
+
+    /// The multiplicative adjective: "single", "double", "triple", then "eleven-tuple".
+    /// Cardinals of more than one word use digits ("100-tuple"); 0 is an error.
+    pub fn tuple_word(&mut self, n: u64) -> Result<String, InflectError> {
+        if n == 0 {
+            return Err(InflectError::BadNumValue(n.to_string()));
+        }
+        let words = tuple_words();
+        let word = usize::try_from(n).ok().and_then(|i| words.get(i));
+        if let Some(word) = word.filter(|w| !w.is_empty()) {
+            return Ok(word.clone());
+        }
+        let cardinal = self.number_to_words(n, &NumberToWordsOptions::default())?;
+        if cardinal.contains(' ') {
+            return Ok(format!("{}-tuple", n));
+        }
+        Ok(format!("{}-tuple", cardinal))
+    }
+
+    /// The name for a group of performers: "solo", "duo", "trio", then "group of eleven".
+    /// 0 is an error.
+    pub fn group_word(&mut self, n: u64) -> Result<String, InflectError> {
+        if n == 0 {
+            return Err(InflectError::BadNumValue(n.to_string()));
+        }
+        let words = group_words();
+        let word = usize::try_from(n).ok().and_then(|i| words.get(i));
+        if let Some(word) = word.filter(|w| !w.is_empty()) {
+            return Ok(word.clone());
+        }
+        Ok(format!(
+            "group of {}",
+            self.number_to_words(n, &NumberToWordsOptions::default())?
+        ))
+    }
+
+    /// Reads an ordinal back into a number, from either words ("twenty-first") or digits
+    /// with a suffix ("113th"). The suffix must agree with the number, so "22th" is an error.
+    pub fn parse_ordinal(&self, text: &str) -> Result<u64, InflectError> {
+        let bad = || InflectError::BadNumValue(text.to_string());
+        let lowered = text.trim().to_lowercase();
+
+        if lowered.starts_with(|c: char| c.is_ascii_digit()) {
+            let split = lowered
+                .find(|c: char| !c.is_ascii_digit() && c != ',')
+                .ok_or_else(bad)?;
+            let (digits, suffix) = lowered.split_at(split);
+            let n: u64 = digits.replace(',', "").parse().map_err(|_| bad())?;
+            if suffix != nth_suffix(n as u128) {
+                return Err(bad());
+            }
+            return Ok(n);
+        }
+
+        let normalized = lowered.split_whitespace().collect::<Vec<&str>>().join(" ");
+        let cardinal = ordinal()
+            .into_iter()
+            .find_map(|(card, ord)| {
+                normalized
+                    .strip_suffix(ord.as_str())
+                    .map(|stem| format!("{}{}", stem, card))
+            })
+            .or_else(|| normalized.strip_suffix("th").map(String::from))
+            .ok_or_else(bad)?;
+        if self.sub_ord(&cardinal) != normalized {
+            return Err(bad());
+        }
+        match self.words_to_number(&cardinal)? {
+            Number::Int(n) => u64::try_from(n).map_err(|_| bad()),
+            Number::Float(_) => Err(bad()),
+        }
+    }
+
+    /// Converts a number to words ("1234" -> "one thousand, two hundred and thirty-four").
+    /// Accepts one sign, comma-grouped digits, a decimal point and an ordinal suffix ("21st");
+    /// anything else, such as "1e3" or NaN, is `BadNumValue`.
+    pub fn number_to_words<T: ToString>(
+        &mut self,
+        num: T,
+        opts: &NumberToWordsOptions,
+    ) -> Result<String, InflectError> {
+        let num = num.to_string();
+        if !is_number_text(strip_ordinal_suffix(num.trim())) {
+            return Err(InflectError::BadNumValue(num));
+        }
+        if let Some(digits) = threshold_digits(&num, opts) {
+            return Ok(digits);
+        }
+        let (sign, numchunks) = self.number_to_words_chunks(&num, opts)?;
+        let signout = sign.map(|s| format!("{} ", s)).unwrap_or_default();
+        let decimal = match opts.decimal_style {
+            DecimalStyle::PlaceValue => Some(opts.andword.as_str()),
+            DecimalStyle::DigitByDigit => opts.decimal.as_deref(),
+        };
+        let valout = if opts.group != 0 {
+            numchunks.join(", ")
+        } else {
+            render_number_chunks(numchunks, decimal, &opts.comma)
+        };
+        Ok(format!("{}{}", signout, valout))
+    }
+
+    /// Like `number_to_words`, but returns the sign and each comma-separated chunk separately.
+    pub fn number_to_words_list<T: ToString>(
+        &mut self,
+        num: T,
+        opts: &NumberToWordsOptions,
+    ) -> Result<Vec<String>, InflectError> {
+        let num = num.to_string();
+        if !is_number_text(strip_ordinal_suffix(num.trim())) {
+            return Err(InflectError::BadNumValue(num));
+        }
+        if let Some(digits) = threshold_digits(&num, opts) {
+            return Ok(vec![digits]);
+        }
+        let (sign, numchunks) = self.number_to_words_chunks(&num, opts)?;
+        Ok(sign
+            .into_iter()
+            .map(String::from)
+            .chain(numchunks)
+            .collect())
+    }
+
+    fn number_to_words_chunks(
+        &mut self,
+        num: &str,
+        opts: &NumberToWordsOptions,
+    ) -> Result<(Option<&'static str>, Vec<String>), InflectError> {
+        let num = if opts.trim_trailing_zeros {
+            trim_decimal_zeros(num)
+        } else {
+            num
+        };
+        if opts.decimal_style == DecimalStyle::PlaceValue && opts.group == 0 {
+            if let (Some(_), Some((int, frac))) = (&opts.decimal, num.split_once('.')) {
+                return self.place_value_chunks(int, frac, opts);
+            }
+        }
+        self.set_number_args(Some(opts.clone()));
+
+        if opts.group > 3 {
+            return Err(InflectError::BadChunkingOption);
+        }
+
+        let nowhite = num.trim_start();
+        let sign = match nowhite.chars().next() {
+            Some('+') => Some("plus"),
+            Some('-') => Some("minus"),
+            _ => None,
+        };
+
+        let mut num = num.to_string();
+        if nth_suff().contains(&num) {
+            num = opts.zero.clone();
+        }
+
+        let myord = nth_suff().contains(tail(&num, 2));
+        if myord {
+            num = chop(&num, 2).to_string();
+        }
+        let mut finalpoint = false;
+        let mut chunks: Vec<String> = if opts.decimal.is_some() {
+            let mut chunks: Vec<String> = if opts.group != 0 {
+                num.split('.').map(String::from).collect()
+            } else {
+                num.splitn(2, '.').map(String::from).collect()
+            };
+            if chunks.len() > 1 && chunks[chunks.len() - 1].is_empty() {
+                chunks.pop();
+                finalpoint = true;
+            }
+            chunks
+        } else {
+            vec![num.clone()]
+        };
+
+        // `first` is 0 when the number starts with its decimal point, as in ".5".
+        let mut first: Option<bool> = Some(true);
+        let mut loopstart = 0;
+        if chunks[0].is_empty() {
+            first = None;
+            if chunks.len() > 1 {
+                loopstart = 1;
+            }
+        }
+
+        let whitespaces_comma = whitespaces_comma();
+        let comma_word = comma_word();
+        for chunk in chunks.iter_mut().skip(loopstart) {
+            let mut digits: String = chunk.chars().filter(|c| c.is_ascii_digit()).collect();
+            if digits.is_empty() {
+                digits = "0".to_string();
+            }
+
+            let mut words = if opts.group == 0 && first != Some(true) {
+                self.enword(&digits, 1)?
+            } else {
+                self.enword(&digits, opts.group)?
+            };
+
+            if let Some(stripped) = words.strip_suffix(", ") {
+                words = stripped.to_string();
+            }
+            words = whitespaces_comma.replace_all(&words, ",").to_string();
+            if opts.group == 0 && first == Some(true) {
+                words = comma_word
+                    .replace(&words, format!(" {} $1", opts.andword).as_str())
+                    .to_string();
+            }
+            words = words.split_whitespace().collect::<Vec<&str>>().join(" ");
+            if first.is_some() {
+                first = Some(false);
+            }
+            *chunk = words;
+        }
+
+        // Groups are always separated by ", " internally; `opts.comma` is applied when rendering.
+        let separator = ", ";
+        let decimal = opts.decimal.clone().unwrap_or_default();
+        let mut numchunks: Vec<String> = Vec::new();
+        if first.is_some() {
+            numchunks = chunks[0].split(separator).map(String::from).collect();
+        }
+
+        if myord && !numchunks.is_empty() {
+            let last = numchunks.len() - 1;
+            numchunks[last] = self.sub_ord(&numchunks[last]);
+        }
+
+        for chunk in chunks.iter().skip(1) {
+            numchunks.push(decimal.clone());
+            numchunks.extend(chunk.split(separator).map(String::from));
+        }
+
+        if finalpoint {
+            numchunks.push(decimal);
+        }
+
+        Ok((sign, numchunks))
+    }
+
+    /// Chunks for a decimal read by place value: the whole part, `andword`, then the
+    /// decimal part as a count of tenths, hundredths and so on.
+    fn place_value_chunks(
+        &mut self,
+        int: &str,
+        frac: &str,
+        opts: &NumberToWordsOptions,
+    ) -> Result<(Option<&'static str>, Vec<String>), InflectError> {
+        let digit_opts = NumberToWordsOptions {
+            decimal_style: DecimalStyle::DigitByDigit,
+            ..opts.clone()
+        };
+        let frac: String = frac.chars().filter(|c| c.is_ascii_digit()).collect();
+        let numerator = frac.trim_start_matches('0');
+        let whole: String = int.chars().filter(|c| c.is_ascii_digit()).collect();
+        if numerator.is_empty() {
+            let int = if whole.is_empty() { "0" } else { int };
+            return self.number_to_words_chunks(int, &digit_opts);
+        }
+        let sign = match int.trim_start().chars().next() {
+            Some('+') => Some("plus"),
+            Some('-') => Some("minus"),
+            _ => None,
+        };
+        let mut chunks = if whole.trim_start_matches('0').is_empty() {
+            Vec::new()
+        } else {
+            self.number_to_words_chunks(&whole, &digit_opts)?.1
+        };
+        let place = decimal_place_name(frac.len())?;
+        let count = self.number_to_words(numerator, &digit_opts)?;
+        if !chunks.is_empty() {
+            chunks.push(opts.andword.clone());
+        }
+        chunks.push(format!(
+            "{} {}",
+            count,
+            self.plural_noun(&place, Some(numerator))
+        ));
+        Ok((sign, chunks))
+    }
+
+    /// Reads English number words back into a number, the inverse of `number_to_words`.
+    /// Accepts "and", hyphens, "a hundred", "zero"/"oh"/"nil", "minus" and "point".
+    /// Values beyond the range of `i128` fail with `NumOutOfRange`.
+    pub fn words_to_number(&self, words: &str) -> Result<Number, InflectError> {
+        let bad = || InflectError::BadNumValue(words.to_string());
+        let lowered = words.to_lowercase().replace(['-', ','], " ");
+        let mut tokens: Vec<&str> = lowered.split_whitespace().collect();
+        let negative = match tokens.first() {
+            Some(&"minus") | Some(&"negative") => {
+                tokens.remove(0);
+                true
+            }
+            Some(&"plus") => {
+                tokens.remove(0);
+                false
+            }
+            _ => false,
+        };
+        let (int_tokens, frac_tokens) = match tokens.iter().position(|&t| t == "point") {
+            Some(i) => (&tokens[..i], Some(&tokens[i + 1..])),
+            None => (&tokens[..], None),
+        };
+        if int_tokens.is_empty() && frac_tokens.is_none()
+            || frac_tokens.is_some_and(|f| f.is_empty())
+        {
+            return Err(bad());
+        }
+
+        let int = words_to_integer(int_tokens).map_err(|e| match e {
+            InflectError::NumOutOfRange => e,
+            _ => bad(),
+        })?;
+        let int = if negative { -int } else { int };
+
+        match frac_tokens {
+            None => Ok(Number::Int(int)),
+            Some(frac_tokens) => {
+                let digits = frac_tokens
+                    .iter()
+                    .map(|&t| digit_word_value(t).map(|d| char::from(b'0' + d as u8)))
+                    .collect::<Option<String>>()
+                    .ok_or_else(bad)?;
+                let sign = if negative { "-" } else { "" };
+                format!("{}{}.{}0", sign, int.abs(), digits)
+                    .parse::<f64>()
+                    .map(Number::Float)
+                    .map_err(|_| bad())
+            }
+        }
+    }
+
+    /// Looks up a registered currency by its code ("usd" or "USD").
+    pub fn currency(&self, code: &str) -> Option<Currency> {
+        self.currencies.get(code).cloned()
+    }
+
+    /// A currency unit name agreeing with `count`: "one penny", "two pence".
+    fn currency_unit(&self, name: &str, plural: &Option<String>, count: &str) -> String {
+        if count == "1" {
+            return name.to_string();
+        }
+        plural
+            .clone()
+            .unwrap_or_else(|| self.plural_noun(name, Some(2)))
+    }
+
+    /// Writes an amount of money in words: "1250.07" in dollars is "one thousand two hundred
+    /// and fifty dollars and seven cents", or "... dollars and 07/100" with
+    /// `MinorStyle::Fraction`. The amount is rounded to the currency's minor digits first.
+    pub fn currency_to_words<T: ToString>(
+        &mut self,
+        amount: T,
+        currency: &Currency,
+        opts: &CurrencyOptions,
+    ) -> Result<String, InflectError> {
+        let amount = amount.to_string().replace(',', "");
+        let (negative, major, minor) = round_decimal(&amount, currency.minor_digits, opts.rounding)
+            .ok_or_else(|| InflectError::BadNumValue(amount.clone()))?;
+        let minor_value = minor.trim_start_matches('0');
+        let minor_value = if minor_value.is_empty() {
+            "0"
+        } else {
+            minor_value
+        };
+
+        let major_words = format!(
+            "{} {}",
+            self.number_to_words(&major, &opts.number)?,
+            self.currency_unit(&currency.major, &currency.major_plural, &major)
+        );
+        let minor_words = match (&currency.minor, opts.minor_style) {
+            (None, _) => None,
+            (Some(_), MinorStyle::Fraction) => {
+                Some(format!("{}/1{}", minor, "0".repeat(currency.minor_digits)))
+            }
+            (Some(_), MinorStyle::Words) if minor_value == "0" => None,
+            (Some(name), MinorStyle::Words) => Some(format!(
+                "{} {}",
+                self.number_to_words(minor_value, &opts.number)?,
+                self.currency_unit(name, &currency.minor_plural, minor_value)
+            )),
+        };
+
+        let words = match minor_words {
+            None => major_words,
+            Some(minor_words) if major == "0" && opts.minor_style == MinorStyle::Words => {
+                minor_words
+            }
+            Some(minor_words) if opts.andword.is_empty() => {
+                format!("{} {}", major_words, minor_words)
+            }
+            Some(minor_words) => format!("{} {} {}", major_words, opts.andword, minor_words),
+        };
+        if negative && (major != "0" || minor_value != "0") {
+            return Ok(format!("minus {}", words));
+        }
+        Ok(words)
+    }
+
+    /// Writes a fraction in words: 3/4 -> "three quarters", 7/64 -> "seven sixty-fourths".
+    pub fn fraction_to_words(
+        &mut self,
+        numerator: i64,
+        denominator: i64,
+        opts: &FractionToWordsOptions,
+    ) -> Result<String, InflectError> {
+        self.fraction_words(&Fraction::new(numerator, denominator), opts)
+    }
+
+    /// Parses and writes a fraction or mixed number: "2 1/2" -> "two and a half".
+    pub fn fraction_str_to_words(
+        &mut self,
+        text: &str,
+        opts: &FractionToWordsOptions,
+    ) -> Result<String, InflectError> {
+        self.fraction_words(&text.parse()?, opts)
+    }
+
+    fn fraction_words(
+        &mut self,
+        fraction: &Fraction,
+        opts: &FractionToWordsOptions,
+    ) -> Result<String, InflectError> {
+        let cardinal = NumberToWordsOptions {
+            comma: "".to_string(),
+            ..Default::default()
+        };
+        let &Fraction {
+            whole,
+            numerator,
+            denominator,
+            ..
+        } = fraction;
+        if denominator == 0 {
+            return Err(InflectError::BadNumValue(format!("{}/0", numerator)));
+        }
+        let denominator_word = match (denominator, opts.fourths) {
+            (2, _) => "half".to_string(),
+            (4, FourthsStyle::Quarters) => "quarter".to_string(),
+            _ => {
+                let cardinal = self.number_to_words(denominator, &cardinal)?;
+                // "hundredth" rather than "one hundredth", but "one hundred and first".
+                let cardinal = match cardinal.strip_prefix("one ") {
+                    Some(scale) if !scale.contains(' ') => scale.to_string(),
+                    _ => cardinal,
+                };
+                self.ordinal(cardinal)
+            }
+        };
+
+        let words = if numerator == 0 || denominator == 1 {
+            let value = whole + if denominator == 1 { numerator } else { 0 };
+            self.number_to_words(value, &cardinal)?
+        } else {
+            let count = if whole > 0 && numerator == 1 {
+                "a".to_string()
+            } else {
+                self.number_to_words(numerator, &cardinal)?
+            };
+            let part = format!(
+                "{} {}",
+                count,
+                self.plural_noun(&denominator_word, Some(numerator))
+            );
+            if whole > 0 {
+                format!("{} and {}", self.number_to_words(whole, &cardinal)?, part)
+            } else {
+                part
+            }
+        };
+        if fraction.negative && words != "zero" {
+            return Ok(format!("minus {}", words));
+        }
+        Ok(words)
+    }
+
+    /// Writes out every number in `text`: cardinals, ordinals ("3rd"), decimals, negatives,
+    /// percentages, currency amounts ("$5"), comma-grouped digits, years after words
+    /// such as "in" or a month name, ratios ("3:1") and clock times with two digits after
+    /// a single colon ("10:45"). Everything else is kept as it was.
+    /// Numbers that are part of a word ("mp3") or joined to other digits by a dot, hyphen or
+    /// slash ("1.2.3", "555-1234", "1/2") are left alone, as are numbers too large to convert.
+    pub fn verbalize_numbers(&mut self, text: &str, opts: &VerbalizeOptions) -> Verbalized {
+        let mut out = String::new();
+        let mut spans = Vec::new();
+        let mut last = 0;
+        for caps in number_token().captures_iter(text) {
+            let whole = caps.get(0).map_or(0..0, |m| m.range());
+            let Some((start, words)) = self.verbalize_token(text, &caps, whole.clone(), opts)
+            else {
+                continue;
+            };
+            out.push_str(&text[last..start]);
+            let output = out.len()..out.len() + words.len();
+            out.push_str(&words);
+            spans.push(VerbalizedSpan {
+                source: start..whole.end,
+                output,
+            });
+            last = whole.end;
+        }
+        out.push_str(&text[last..]);
+        Verbalized { text: out, spans }
+    }
+
+    /// The words for one number found by `verbalize_numbers`, and where they start in the
+    /// source, or None if the number should be left alone.
+    fn verbalize_token(
+        &mut self,
+        text: &str,
+        caps: &regex::Captures,
+        whole: std::ops::Range<usize>,
+        opts: &VerbalizeOptions,
+    ) -> Option<(usize, String)> {
+        let group = |i: usize| caps.get(i).map_or("", |m| m.as_str());
+        let (lead_sign, symbol, sign, digits, parts, suffix) =
+            (group(1), group(2), group(3), group(4), group(5), group(6));
+        let before = &text[..whole.start];
+        let after = &text[whole.end..];
+        // A hyphen straight after a word is not a minus sign: "COVID-19".
+        let glued_hyphen = before.ends_with(char::is_alphanumeric);
+        let (start, negative) = if lead_sign.is_empty() {
+            (whole.start, !sign.is_empty())
+        } else if glued_hyphen && symbol.is_empty() {
+            (whole.start + 1, false)
+        } else {
+            (whole.start, true)
+        };
+        let lead = &text[..start];
+        let prev = lead.chars().next_back();
+        let next = after.chars().next();
+        // Digits joined by a dot, hyphen or slash ("1.2.3", "555-1234", "1/2") are left alone.
+        let joiners = ['.', '-', '/'];
+        let is_digit = |c: char| c.is_ascii_digit();
+        if prev.is_some_and(char::is_alphanumeric)
+            || next.is_some_and(char::is_alphanumeric)
+            || (after.starts_with(joiners) && after[1..].starts_with(is_digit))
+            || (lead.ends_with(joiners) && lead[..lead.len() - 1].ends_with(is_digit))
+        {
+            return None;
+        }
+
+        if !is_comma_grouped(digits.split('.').next().unwrap_or_default()) {
+            return None;
+        }
+        let plain = digits.replace(',', "");
+        let signed = format!("{}{}", if negative { "-" } else { "" }, plain);
+        if !parts.is_empty() {
+            if !symbol.is_empty() || !suffix.is_empty() || negative {
+                return None;
+            }
+            // One colon and two digits is a clock time ("10:45"), never a ratio; anything
+            // that is not a valid time is left alone.
+            if parts.len() == 3 {
+                if digits.len() > 2 {
+                    return None;
+                }
+                let (hour, minute) = (plain.parse().ok()?, parts[1..].parse().ok()?);
+                return self
+                    .time_to_words(hour, minute, TimeStyle::Digital)
+                    .ok()
+                    .map(|words| (start, words));
+            }
+            return self
+                .ratio_to_words(&format!("{}{}", plain, parts), &opts.number)
+                .ok()
+                .map(|words| (start, words));
+        }
+        let words = match (symbol, suffix) {
+            ("", "%") => self.percent_to_words(&signed, &opts.percent),
+            ("", "") => match plain.parse::<i32>() {
+                Ok(year)
+                    if opts.years
+                        && plain.len() == 4
+                        && !negative
+                        && digits == plain
+                        && year_context(before, after) =>
+                {
+                    self.year_to_words(year, &opts.year)
+                }
+                _ => self.number_to_words(&signed, &opts.number),
+            },
+            ("", _)
+                if !plain.contains('.')
+                    && plain.parse::<u128>().is_ok_and(|n| nth_suffix(n) == suffix) =>
+            {
+                self.ordinal_words(&signed)
+            }
+            (symbol, "") => {
+                let currency = self.currency(currency_symbol_code(symbol)?)?;
+                self.currency_to_words(&signed, &currency, &opts.currency)
+            }
+            _ => return None,
+        };
+        words.ok().map(|words| (start, words))
+    }
+
+    /// Reads a percentage: "45%" or 45 is "forty-five percent", and "0.5%" is "zero point
+    /// five percent", or "half a percent" with `fractions`.
+    pub fn percent_to_words<T: ToString>(
+        &mut self,
+        value: T,
+        opts: &PercentOptions,
+    ) -> Result<String, InflectError> {
+        let value = value.to_string();
+        let value = value.trim().trim_end_matches('%').trim_end();
+        let word = match opts.word {
+            PercentWord::Percent => "percent",
+            PercentWord::PerCent => "per cent",
+        };
+        if !is_number_text(value) {
+            return Err(InflectError::BadNumValue(value.to_string()));
+        }
+        let sign = if value.starts_with('-') { "minus " } else { "" };
+        let amount: f64 = value
+            .trim_start_matches(['-', '+'])
+            .replace(',', "")
+            .parse()
+            .unwrap_or_default();
+        let fraction = [
+            (0.5, "half a"),
+            (0.25, "a quarter of a"),
+            (0.75, "three quarters of a"),
+        ]
+        .iter()
+        .find(|&&(f, _)| f == amount)
+        .map(|&(_, words)| words);
+        if let Some(fraction) = fraction.filter(|_| opts.fractions) {
+            return Ok(format!("{}{} {}", sign, fraction, word));
+        }
+        Ok(format!(
+            "{} {}",
+            self.number_to_words(value, &opts.number)?,
+            word
+        ))
+    }
+
+    /// Reads a ratio: "3:1" is "three to one", "2:1:1" is "two to one to one".
+    pub fn ratio_to_words(
+        &mut self,
+        ratio: &str,
+        opts: &NumberToWordsOptions,
+    ) -> Result<String, InflectError> {
+        let parts: Vec<&str> = ratio.split(':').map(str::trim).collect();
+        if parts.len() < 2 || parts.iter().any(|p| p.is_empty()) {
+            return Err(InflectError::BadNumValue(ratio.to_string()));
+        }
+        let mut words = Vec::new();
+        for part in parts {
+            if !is_number_text(part) {
+                return Err(InflectError::BadNumValue(ratio.to_string()));
+            }
+            words.push(self.number_to_words(part, opts)?);
+        }
+        Ok(words.join(" to "))
+    }
+
+    /// Reads the last two digits of a year: "eighty-four", "oh-five", or "hundred" for "00".
+    fn year_pair(&self, pair: usize) -> Result<String, InflectError> {
+        Ok(match pair {
+            0 => "hundred".to_string(),
+            1..=9 => format!("oh-{}", unit()[pair]),
+            _ => self.tenfn(pair / 10, pair % 10, 0)?.trim().to_string(),
+        })
+    }
+
+    /// Reads a year the way it is spoken: 1984 -> "nineteen eighty-four", 1900 ->
+    /// "nineteen hundred", 1101 -> "eleven oh-one". Negative years get a BC or BCE suffix,
+    /// and there is no year zero. Years past 9999 are read as cardinals.
+    pub fn year_to_words(
+        &mut self,
+        year: i32,
+        opts: &YearToWordsOptions,
+    ) -> Result<String, InflectError> {
+        if year == 0 {
+            return Err(InflectError::BadNumValue(year.to_string()));
+        }
+        let cardinal = NumberToWordsOptions {
+            andword: opts.andword.clone(),
+            ..Default::default()
+        };
+        self.set_number_args(Some(cardinal.clone()));
+        let abs = year.unsigned_abs() as usize;
+        let (hi, lo) = (abs / 100, abs % 100);
+        let words = if abs >= 10000 {
+            self.number_to_words(abs, &cardinal)?
+        } else if abs < 100 {
+            self.tenfn(lo / 10, lo % 10, 0)?.trim().to_string()
+        } else if abs.is_multiple_of(1000) {
+            format!("{} thousand", unit()[abs / 1000])
+        } else if abs > 1000 && abs % 1000 < 10 && opts.millennium == MillenniumStyle::Thousand {
+            let andword = match opts.andword.as_str() {
+                "" => String::new(),
+                andword => format!(" {}", andword),
+            };
+            format!(
+                "{} thousand{} {}",
+                unit()[abs / 1000],
+                andword,
+                unit()[abs % 1000]
+            )
+        } else if hi < 10 {
+            format!("{} {}", unit()[hi], self.year_pair(lo)?)
+        } else {
+            format!(
+                "{} {}",
+                self.tenfn(hi / 10, hi % 10, 0)?.trim(),
+                self.year_pair(lo)?
+            )
+        };
+        if year > 0 {
+            return Ok(words);
+        }
+        let era = match opts.era {
+            Era::BcAd => "BC",
+            Era::BceCe => "BCE",
+        };
+        Ok(format!("{} {}", words, era))
+    }
+
+    /// Describes a length of time: "2 hours, 1 minute and 30 seconds", or with `words` and
+    /// `max_parts: Some(2)`, "two hours and one minute". Units outside `largest` and
+    /// `smallest` are folded into their neighbours, and zero parts are left out.
+    pub fn duration_to_words(
+        &mut self,
+        duration: std::time::Duration,
+        opts: &DurationOptions,
+    ) -> Result<String, InflectError> {
+        let smallest = opts.smallest.min(opts.largest);
+        let units: Vec<TimeUnit> = TimeUnit::ALL
+            .into_iter()
+            .filter(|&u| u <= opts.largest && u >= smallest)
+            .collect();
+        let split = |total: u128, last: TimeUnit| -> Vec<(TimeUnit, u128)> {
+            let mut rest = total;
+            let mut parts = Vec::new();
+            for &unit in units.iter().filter(|&&u| u >= last) {
+                parts.push((unit, rest / unit.millis()));
+                rest %= unit.millis();
+            }
+            parts
+        };
+
+        // Find the last unit shown, then round everything below it into that unit.
+        let millis = duration.as_millis();
+        let mut last = smallest;
+        if let Some(max) = opts.max_parts.filter(|&m| m > 0) {
+            let parts = split(millis, smallest);
+            if let Some(first) = parts.iter().position(|&(_, n)| n > 0) {
+                last = parts[(first + max - 1).min(parts.len() - 1)].0;
+            }
+        }
+        let size = last.millis();
+        let (whole, rest) = (millis / size, millis % size);
+        let round_up = match opts.rounding {
+            Rounding::Down => false,
+            Rounding::HalfUp => rest * 2 >= size,
+            Rounding::HalfEven => rest * 2 > size || (rest * 2 == size && whole % 2 == 1),
+        };
+        let total = (whole + u128::from(round_up)) * size;
+
+        let mut parts: Vec<(TimeUnit, u128)> = split(total, last)
+            .into_iter()
+            .filter(|&(_, n)| n > 0)
+            .collect();
+        if parts.is_empty() {
+            parts.push((last, 0));
+        }
+        if let Some(max) = opts.max_parts.filter(|&m| m > 0) {
+            parts.truncate(max);
+        }
+
+        let cardinal = NumberToWordsOptions {
+            comma: "".to_string(),
+            ..Default::default()
+        };
+        let mut phrases = Vec::new();
+        for (unit, n) in parts {
+            let count = if opts.words {
+                self.number_to_words(n, &cardinal)?
+            } else {
+                n.to_string()
+            };
+            phrases.push(format!(
+                "{} {}",
+                count,
+                self.plural_noun(unit.name(), Some(n))
+            ));
+        }
+        let phrase = self.join(&phrases, &opts.join);
+        Ok(match opts.relative {
+            Some(Relative::Future) => format!("in {}", phrase),
+            Some(Relative::Past) => format!("{} ago", phrase),
+            None => phrase,
+        })
+    }
+
+    /// Describes a large count compactly: 1234567 is "1.2 million", or with hedges "about
+    /// 1.2 million", "over 3 thousand", "nearly 4 billion" or "a few hundred". The noun
+    /// agrees with the whole count, so it is "1 million users".
+    pub fn approx_number(&self, n: u128, opts: &ApproxOptions) -> String {
+        let digits = n.to_string();
+        let rounded = round_significant(&digits, opts.significant, opts.rounding);
+        let value: u128 = rounded.parse().unwrap_or(n);
+        let mill = mill();
+
+        let phrase = if opts.hedge == Hedge::Vague && n >= 100 {
+            let k = (digits.len() - 1) / 3;
+            let (scale, count) = if k == 0 {
+                ("hundred", n / 100)
+            } else {
+                (mill[k].trim(), n / 1000u128.pow(k as u32))
+            };
+            match count {
+                1 => format!("about a {}", scale),
+                2..=4 => format!("a few {}", scale),
+                5..=9 => format!("several {}", scale),
+                10..=99 => format!("tens of {}", self.plural_noun(scale, Some(2))),
+                _ => format!("hundreds of {}", self.plural_noun(scale, Some(2))),
+            }
+        } else {
+            let k = (rounded.len() - 1) / 3;
+            let number = if k == 0 {
+                rounded.clone()
+            } else {
+                let (int, frac) = rounded.split_at(rounded.len() - 3 * k);
+                let frac = frac.trim_end_matches('0');
+                let mantissa = if frac.is_empty() {
+                    int.to_string()
+                } else {
+                    format!("{}.{}", int, frac)
+                };
+                format!("{}{}", mantissa, mill[k])
+            };
+            let hedge = match opts.hedge {
+                Hedge::About => "about ",
+                Hedge::Over => "over ",
+                Hedge::Nearly => "nearly ",
+                Hedge::Auto if value < n => "over ",
+                Hedge::Auto if value > n => "nearly ",
+                _ => "",
+            };
+            format!("{}{}", hedge, number)
+        };
+        // "tens of millions" is itself a noun phrase: "tens of millions of users".
+        let of = if phrase.contains(" of ") { " of" } else { "" };
+        match &opts.noun {
+            Some(noun) => format!("{}{} {}", phrase, of, self.plural_noun(noun, Some(value))),
+            None => phrase,
+        }
+    }
+
+    /// Writes a number in Roman numerals: 14 -> "XIV". Zero has no numeral and is an
+    /// error, as are numbers past the range of `style.large`.
+    pub fn to_roman(&self, n: u64, style: RomanStyle) -> Result<String, InflectError> {
+        let unicode = style.letters == RomanLetters::Unicode;
+        let numeral = match (n, style.large) {
+            (0, _) => return Err(InflectError::BadNumValue(n.to_string())),
+            (1..4000, _) => roman_digits(n, 1000, unicode),
+            (4000..4_000_000, RomanLarge::Vinculum) => {
+                let thousands: String = roman_digits(n / 1000, 1000, unicode)
+                    .chars()
+                    .flat_map(|c| [c, '\u{305}'])
+                    .collect();
+                format!("{}{}", thousands, roman_digits(n % 1000, 1000, unicode))
+            }
+            (4000..400_000, RomanLarge::Apostrophus) => {
+                let numeral = roman_digits(n, 100000, unicode);
+                if unicode {
+                    numeral.replace('Ⅿ', "ↀ")
+                } else {
+                    numeral.replace('M', "CIↃ")
+                }
+            }
+            _ => return Err(InflectError::NumOutOfRange),
+        };
+        Ok(match style.letters {
+            RomanLetters::Lower => numeral.to_lowercase().replace('Ↄ', "ↄ"),
+            _ => numeral,
+        })
+    }
+
+    /// Reads a Roman numeral written exactly as `to_roman` would write it, in any of its
+    /// styles. "IIII" and "IC" are errors; see `from_roman_lenient`.
+    pub fn from_roman(&self, text: &str) -> Result<u64, InflectError> {
+        let trimmed = text.trim();
+        let n = self.from_roman_lenient(trimmed)?;
+        // The reversed C of the ASCII apostrophus forms is in the same Unicode block.
+        let unicode = |c: char| ('\u{2160}'..='\u{2188}').contains(&c) && !"Ↄↄ".contains(c);
+        let letters = if trimmed.chars().any(unicode) {
+            RomanLetters::Unicode
+        } else if trimmed.chars().any(|c| c.is_lowercase()) {
+            RomanLetters::Lower
+        } else {
+            RomanLetters::Upper
+        };
+        let large = if trimmed.contains(['Ↄ', 'ↄ', 'ↁ', 'ↂ', 'ↇ', 'ↈ']) {
+            RomanLarge::Apostrophus
+        } else {
+            RomanLarge::Vinculum
+        };
+        match self.to_roman(n, RomanStyle { letters, large }) {
+            Ok(canonical) if canonical == trimmed => Ok(n),
+            _ => Err(InflectError::BadNumValue(text.to_string())),
+        }
+    }
+
+    /// Reads any sequence of Roman numerals, in any case and mix of styles, adding each
+    /// symbol and subtracting those written before a larger one: "IIII" is 4, "IC" is 99.
+    pub fn from_roman_lenient(&self, text: &str) -> Result<u64, InflectError> {
+        let bad = || InflectError::BadNumValue(text.to_string());
+        let values = roman_values(text.trim())
+            .filter(|v| !v.is_empty())
+            .ok_or_else(bad)?;
+        let mut total: i128 = 0;
+        for (i, &value) in values.iter().enumerate() {
+            if values[i + 1..].first().is_some_and(|&next| next > value) {
+                total -= i128::from(value);
+            } else {
+                total += i128::from(value);
+            }
+        }
+        u64::try_from(total).ok().filter(|&n| n > 0).ok_or_else(bad)
+    }
+
+    /// Reads the regnal number in a name: "Henry VIII" -> "Henry the Eighth", "King Henry
+    /// VIII's" -> "King Henry the Eighth's". The first capitalised Roman numeral that follows
+    /// a capitalised word is replaced, so "Henry and I" is an error.
+    pub fn regnal_to_words(&mut self, name: &str) -> Result<String, InflectError> {
+        let mut words: Vec<String> = name.split(' ').map(String::from).collect();
+        for i in 1..words.len() {
+            if !starts_upper(&words[i - 1]) {
+                continue;
+            }
+            let word = words[i].clone();
+            let stem = word.trim_end_matches(|c: char| c.is_ascii_punctuation());
+            let numeral = stem
+                .strip_suffix("'s")
+                .or_else(|| stem.strip_suffix("’s"))
+                .unwrap_or(stem);
+            if numeral.is_empty() || !numeral.chars().all(|c| "IVXLCDM".contains(c)) {
+                continue;
+            }
+            let Ok(n) = self.from_roman(numeral) else {
+                continue;
+            };
+            let ordinal = capitalize_first(&self.ordinal_words(n)?);
+            words[i] = format!("the {}{}", ordinal, &word[numeral.len()..]);
+            return Ok(words.join(" "));
+        }
+        Err(InflectError::BadNumValue(name.to_string()))
+    }
+
+    /// Writes an amount as on a cheque: "1250" is "One Thousand Two Hundred Fifty and
+    /// 00/100". There is no "and" inside the whole part, and the cents are always shown as
+    /// a fraction of 100. Negative amounts are an error.
+    pub fn cheque_words<T: ToString>(
+        &mut self,
+        amount: T,
+        opts: &ChequeOptions,
+    ) -> Result<String, InflectError> {
+        let amount = amount.to_string().replace(',', "");
+        let places = opts.currency.as_ref().map_or(2, |c| c.minor_digits);
+        let bad = || InflectError::BadNumValue(amount.clone());
+        let (negative, whole, minor) =
+            round_decimal(&amount, places, opts.rounding).ok_or_else(bad)?;
+        if negative && (whole != "0" || minor.contains(|c| c != '0')) {
+            return Err(bad());
+        }
+        let cardinal = NumberToWordsOptions {
+            andword: "".to_string(),
+            comma: "".to_string(),
+            ..Default::default()
+        };
+        let mut words = self.number_to_words(&whole, &cardinal)?;
+        if let Some(currency) = &opts.currency {
+            words = format!(
+                "{} {}",
+                words,
+                self.currency_unit(&currency.major, &currency.major_plural, &whole)
+            );
+        }
+        let words = match opts.casing {
+            Casing::Title => title_case(&words),
+            Casing::Upper => words.to_uppercase(),
+        };
+        let and = match opts.casing {
+            Casing::Title => "and",
+            Casing::Upper => "AND",
+        };
+        let mut out = if places == 0 {
+            words
+        } else {
+            format!("{} {} {}/1{}", words, and, minor, "0".repeat(places))
+        };
+        if let Some(width) = opts.width {
+            let len = out.chars().count();
+            out.extend(std::iter::repeat_n(opts.pad, width.saturating_sub(len)));
+        }
+        Ok(out)
+    }
+
+    /// Writes a number as a style guide would: spelled out ("nine", "thirty-five thousand")
+    /// or as digits ("10", "12,500"). Decimals are always digits except at the start of a
+    /// sentence.
+    pub fn format_number<T: ToString>(
+        &mut self,
+        n: T,
+        style: &NumberStyle,
+        position: Position,
+    ) -> Result<String, InflectError> {
+        let num = n.to_string();
+        let trimmed = num.trim();
+        if !is_number_text(trimmed) {
+            return Err(InflectError::BadNumValue(num));
+        }
+        let unsigned = trimmed.strip_prefix(['-', '+']).unwrap_or(trimmed);
+        let digits = unsigned.replace(',', "");
+        // None for decimals, which are never spelled out mid-sentence.
+        let whole: Option<u128> = digits.parse().ok();
+
+        let round = |v: u128| {
+            let mut scale: u128 = 100;
+            while scale <= v {
+                if v.is_multiple_of(scale) && v / scale <= u128::from(style.spell_up_to) {
+                    return true;
+                }
+                // Hundreds, then thousands, millions and so on.
+                let factor = if scale == 100 { 10 } else { 1000 };
+                match scale.checked_mul(factor) {
+                    Some(next) => scale = next,
+                    None => break,
+                }
+            }
+            false
+        };
+        let spell = position == Position::SentenceStart
+            || whole.is_some_and(|v| {
+                v <= u128::from(style.spell_up_to) || (style.spell_round && round(v))
+            });
+        if spell {
+            let words = self.number_to_words(trimmed.replace(',', ""), &style.number)?;
+            return Ok(match position {
+                Position::SentenceStart => capitalize_first(&words),
+                Position::Inline => words,
+            });
+        }
+        if !style.group_digits {
+            return Ok(trimmed.replace(',', ""));
+        }
+        let sign = &trimmed[..trimmed.len() - unsigned.len()];
+        let (int, frac) = match digits.split_once('.') {
+            Some((int, frac)) => (int, format!(".{}", frac)),
+            None => (digits.as_str(), String::new()),
+        };
+        Ok(format!(
+            "{}{}{}",
+            sign,
+            style.number.numbering.group_digits(int),
+            frac
+        ))
+    }
+
+    /// Phrases a range of counts with its noun: (1, 3) is "1–3 results" or "between three
+    /// and five files"; an open end gives "up to 10 items" (no lower bound) or "more than
+    /// one hundred users" (no upper bound, which excludes `lo` itself). Equal bounds give
+    /// a single count.
+    pub fn range(
+        &mut self,
+        lo: Option<u64>,
+        hi: Option<u64>,
+        noun: &str,
+        style: RangeStyle,
+    ) -> Result<String, InflectError> {
+        let bad = || InflectError::BadNumValue(format!("{:?}..{:?}", lo, hi));
+        let count = match (lo, hi, style.agreement) {
+            (Some(lo), Some(hi), _) if lo > hi => return Err(bad()),
+            (None, None, _) => return Err(bad()),
+            (_, _, RangeAgreement::Plural) => 2,
+            (_, Some(hi), _) | (Some(hi), None, _) => hi,
+        };
+        let noun = self.plural_noun(noun, Some(count));
+        let cardinal = NumberToWordsOptions {
+            comma: "".to_string(),
+            ..Default::default()
+        };
+        let mut number = |n: u64| -> Result<String, InflectError> {
+            if style.words {
+                self.number_to_words(n, &cardinal)
+            } else {
+                Ok(n.to_string())
+            }
+        };
+        let phrase = match (lo, hi) {
+            (Some(lo), Some(hi)) if lo == hi => number(lo)?,
+            (Some(lo), Some(hi)) => match (style.form, style.words) {
+                (RangeForm::Between, _) => format!("between {} and {}", number(lo)?, number(hi)?),
+                (RangeForm::Dash, true) => format!("{} to {}", number(lo)?, number(hi)?),
+                (RangeForm::Dash, false) => format!("{}–{}", lo, hi),
+            },
+            (None, Some(hi)) => format!("up to {}", number(hi)?),
+            (Some(lo), None) => format!("more than {}", number(lo)?),
+            (None, None) => return Err(bad()),
+        };
+        Ok(format!("{} {}", phrase, noun))
+    }
+
+    /// Writes a date in one of the usual English styles; see `DateStyle`. Years before
+    /// year one are negative and get "BC" ("15 March 44 BC").
+    pub fn date_to_words(
+        &mut self,
+        year: i32,
+        month: u32,
+        day: u32,
+        style: DateStyle,
+    ) -> Result<String, InflectError> {
+        if year == 0 || !(1..=12).contains(&month) || day == 0 || day > days_in_month(year, month) {
+            return Err(InflectError::BadNumValue(format!(
+                "{}-{:02}-{:02}",
+                year, month, day
+            )));
+        }
+        let month = capitalize(&month_names()[month as usize - 1]);
+        let year_opts = YearToWordsOptions::default();
+        let year_digits = if year < 0 {
+            format!("{} BC", year.unsigned_abs())
+        } else {
+            year.to_string()
+        };
+        Ok(match style {
+            DateStyle::Us => format!(
+                "{} {}{}, {}",
+                month,
+                day,
+                nth_suffix(day.into()),
+                year_digits
+            ),
+            DateStyle::UsSpelled => format!(
+                "{} {}, {}",
+                month,
+                self.ordinal_words(day)?,
+                self.year_to_words(year, &year_opts)?
+            ),
+            DateStyle::Uk => format!("{} {} {}", day, month, year_digits),
+            DateStyle::UkSpelled => format!(
+                "the {} of {}, {}",
+                self.ordinal_words(day)?,
+                month,
+                self.year_to_words(year, &year_opts)?
+            ),
+        })
+    }
+
+    /// The name of an hour on a twelve-hour clock, with noon and midnight for 12 and 0.
+    fn clock_hour(&self, hour: usize) -> Result<String, InflectError> {
+        Ok(match hour % 24 {
+            0 => "midnight".to_string(),
+            12 => "noon".to_string(),
+            h => self
+                .tenfn((h % 12) / 10, h % 12 % 10, 0)?
+                .trim()
+                .to_string(),
+        })
+    }
+
+    /// Reads a time of day, given on a 24-hour clock. Noon and midnight are named in the
+    /// digital and traditional styles: 12:00 is "noon", 23:45 "a quarter to midnight".
+    pub fn time_to_words(
+        &mut self,
+        hour: u32,
+        minute: u32,
+        style: TimeStyle,
+    ) -> Result<String, InflectError> {
+        if hour > 23 || minute > 59 {
+            return Err(InflectError::BadNumValue(format!(
+                "{:02}:{:02}",
+                hour, minute
+            )));
+        }
+        self.set_number_args(Some(NumberToWordsOptions::default()));
+        let (hour, minute) = (hour as usize, minute as usize);
+        let minutes = |e: &Engine, m: usize| -> Result<String, InflectError> {
+            Ok(e.tenfn(m / 10, m % 10, 0)?.trim().to_string())
+        };
+        Ok(match style {
+            TimeStyle::Military => {
+                let hours = match hour {
+                    0 => "zero".to_string(),
+                    _ => self.year_pair(hour)?,
+                };
+                format!("{} {} hours", hours, self.year_pair(minute)?)
+            }
+            _ if minute == 0 && (hour == 0 || hour == 12) => self.clock_hour(hour)?,
+            TimeStyle::Digital if minute == 0 => format!("{} o'clock", self.clock_hour(hour)?),
+            TimeStyle::Digital => {
+                // Digital readings are of the clock face, so 12:30 is "twelve thirty".
+                let hours = match hour % 12 {
+                    0 => "twelve".to_string(),
+                    _ => self.clock_hour(hour)?,
+                };
+                format!("{} {}", hours, self.year_pair(minute)?)
+            }
+            TimeStyle::Traditional => {
+                let (offset, relation, hour) = match minute {
+                    0 => return Ok(format!("{} o'clock", self.clock_hour(hour)?)),
+                    1..=30 => (minute, "past", hour),
+                    _ => (60 - minute, "to", hour + 1),
+                };
+                let offset = match offset {
+                    15 => "a quarter".to_string(),
+                    30 => "half".to_string(),
+                    m if m.is_multiple_of(5) => minutes(self, m)?,
+                    m => format!(
+                        "{} {}",
+                        minutes(self, m)?,
+                        self.plural_noun("minute", Some(m))
+                    ),
+                };
+                format!("{} {} {}", offset, relation, self.clock_hour(hour)?)
+            }
+        })
+    }
+}
+
+/// A count that selects between singular and plural forms.
+///
+/// Every integer width, `f32`/`f64`, and strings ("1", "1.50", "some") convert into it.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Count {
+    Int(i128),
+    Float(f64),
+    Str(String),
+    Category(PluralCategory),
+}
+
+/// A CLDR plural category. English cardinals only use `One` and `Other`, but a category
+/// chosen upstream by an i18n layer can be passed anywhere a count is accepted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PluralCategory {
+    Zero,
+    One,
+    Two,
+    Few,
+    Many,
+    Other,
+}
+
+impl From<PluralCategory> for Count {
+    fn from(category: PluralCategory) -> Self {
+        Count::Category(category)
+    }
+}
+
+/// Splits a numeric string into the CLDR operands `i` (integer digits) and `v` (number of
+/// visible fraction digits), or None if it is not a plain decimal number.
+fn cldr_operands(s: &str) -> Option<(String, usize)> {
+    let s = s.trim();
+    let s = s.strip_prefix(['-', '+']).unwrap_or(s);
+    let (int, frac) = s.split_once('.').unwrap_or((s, ""));
+    if int.is_empty() && frac.is_empty() {
+        return None;
+    }
+    if !int.chars().all(|c| c.is_ascii_digit()) || !frac.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    let int = int.trim_start_matches('0');
+    Some((
+        if int.is_empty() { "0" } else { int }.to_string(),
+        frac.len(),
+    ))
+}
+
+macro_rules! count_from_int {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Count {
+                fn from(n: $t) -> Self {
+                    Count::Int(i128::try_from(n).unwrap_or(i128::MAX))
+                }
+            }
+        )*
+    };
+}
+
+count_from_int!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+impl From<f32> for Count {
+    fn from(n: f32) -> Self {
+        Count::Float(n as f64)
+    }
+}
+
+impl From<f64> for Count {
+    fn from(n: f64) -> Self {
+        Count::Float(n)
+    }
+}
+
+impl From<String> for Count {
+    fn from(s: String) -> Self {
+        Count::Str(s)
+    }
+}
+
+impl From<&String> for Count {
+    fn from(s: &String) -> Self {
+        Count::Str(s.clone())
     }
 }
 
-impl From<&str> for IntOrString {
+impl From<&str> for Count {
     fn from(s: &str) -> Self {
-        IntOrString::Str(s.to_string())
+        Count::Str(s.to_string())
     }
 }
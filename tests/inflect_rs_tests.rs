@@ -103,7 +103,7 @@ fn test_engine_gender() {
 fn test_engine_get_count() {
     let mut e = Engine::new();
 
-    // i32 Into<IntOrString>>
+    // i32 Into<Count>
     for i in -1e2 as i32..1e2 as i32 {
         assert_eq!(e.get_count(Some(i)), i);
     }
@@ -124,3 +124,1503 @@ fn test_engine_get_count() {
     e.persistent_count = Some(3);
     assert_eq!(e.get_count::<i32>(None), 3);
 }
+
+#[test]
+fn test_plural_noun() {
+    let e = Engine::new();
+    assert_eq!(e.plural_noun::<i32>("cat", None), "cats");
+    assert_eq!(e.plural_noun::<i32>("child", None), "children");
+    assert_eq!(e.plural_noun::<i32>("knife", None), "knives");
+    assert_eq!(e.plural_noun::<i32>("city", None), "cities");
+    assert_eq!(e.plural_noun::<i32>("potato", None), "potatoes");
+    assert_eq!(
+        e.plural_noun::<i32>("mother-in-law", None),
+        "mothers-in-law"
+    );
+    assert_eq!(
+        e.plural_noun::<i32>("Governor General", None),
+        "Governors General"
+    );
+    assert_eq!(
+        e.plural_noun::<i32>("major general", None),
+        "major generals"
+    );
+    assert_eq!(e.plural_noun::<i32>("to it", None), "to them");
+    assert_eq!(e.plural_noun("cat", Some(1)), "cat");
+    assert_eq!(e.plural_noun("corpus", Some(2)), "corpuses");
+}
+
+#[test]
+fn test_singular_noun() {
+    let e = Engine::new();
+    assert_eq!(
+        e.singular_noun::<i32>("cats", None, None),
+        Some("cat".to_string())
+    );
+    assert_eq!(
+        e.singular_noun::<i32>("corpora", None, None),
+        Some("corpus".to_string())
+    );
+    assert_eq!(
+        e.singular_noun::<i32>("hooves", None, None),
+        Some("hoof".to_string())
+    );
+    assert_eq!(
+        e.singular_noun::<i32>("people", None, None),
+        Some("person".to_string())
+    );
+    assert_eq!(
+        e.singular_noun::<i32>("them", None, Some("feminine")),
+        Some("her".to_string())
+    );
+    assert_eq!(e.singular_noun::<i32>("cacti", None, None), None);
+}
+
+#[test]
+fn test_plural_verb() {
+    let e = Engine::new();
+    assert_eq!(e.plural_verb::<i32>("is", None), "are");
+    assert_eq!(e.plural_verb::<i32>("doesn't", None), "don't");
+    assert_eq!(e.plural_verb::<i32>("catches", None), "catch");
+    assert_eq!(e.plural_verb::<i32>("looks at", None), "look at");
+    assert_eq!(e.plural_verb("runs", Some(1)), "runs");
+}
+
+#[test]
+fn test_variants() {
+    let mut e = Engine::new();
+    assert_eq!(
+        e.plural_noun_variants("corpus"),
+        vec!["corpuses", "corpora"]
+    );
+    assert_eq!(e.plural_noun_variants("index"), vec!["indexes", "indices"]);
+    assert_eq!(
+        e.plural_noun_variants("Octopus"),
+        vec!["Octopuses", "Octopodes"]
+    );
+    assert_eq!(e.plural_noun_variants("cat"), vec!["cats"]);
+    assert_eq!(e.singular_noun_variants("indices"), vec!["index"]);
+    assert_eq!(e.plural_verb_variants("is"), vec!["are"]);
+
+    e.variant_preference = VariantPreference::ClassicalFirst;
+    assert_eq!(e.plural_noun_variants("index"), vec!["indices", "indexes"]);
+    assert_eq!(e.plural_noun_variants("hoof"), vec!["hooves", "hoofs"]);
+}
+
+#[test]
+fn test_classical_names() {
+    let mut e = Engine::new();
+    assert_eq!(e.plural_noun::<i32>("Jones", None), "Joneses");
+    assert_eq!(e.plural_noun::<i32>("Sims", None), "Simses");
+    assert_eq!(e.plural_noun::<i32>("Mary", None), "Marys");
+    assert_eq!(
+        e.singular_noun::<i32>("Joneses", None, None),
+        Some("Jones".to_string())
+    );
+    assert_eq!(
+        e.singular_noun::<i32>("Simses", None, None),
+        Some("Sims".to_string())
+    );
+    assert_eq!(
+        e.singular_noun::<i32>("Houses", None, None),
+        Some("House".to_string())
+    );
+
+    e.classical_dict.insert("names".to_string(), false);
+    assert_eq!(e.plural_noun::<i32>("Sally", None), "Sallies");
+    assert_eq!(e.plural_noun::<i32>("bus", None), "buses");
+}
+
+#[test]
+fn test_classical_ancient() {
+    let mut e = Engine::new();
+    assert_eq!(e.plural_noun::<i32>("brother", None), "brothers");
+    assert_eq!(e.plural_noun::<i32>("cow", None), "cows");
+
+    e.classical_dict.insert("ancient".to_string(), true);
+    assert_eq!(e.plural_noun::<i32>("brother", None), "brethren");
+    assert_eq!(e.plural_noun::<i32>("cow", None), "kine");
+    assert_eq!(e.plural_noun::<i32>("turf", None), "turves");
+    assert_eq!(e.plural_noun::<i32>("beef", None), "beeves");
+    assert_eq!(e.plural_noun::<i32>("Brother", None), "Brethren");
+    assert_eq!(e.plural_noun::<i32>("cat", None), "cats");
+    assert_eq!(e.plural_noun::<i32>("corpus", None), "corpuses");
+    assert_eq!(e.plural_noun::<i32>("octopus", None), "octopuses");
+}
+
+#[test]
+fn test_classical_herd() {
+    let mut e = Engine::new();
+    assert_eq!(e.plural_noun::<i32>("bison", None), "bisons");
+    assert_eq!(e.plural_noun::<i32>("wildebeest", None), "wildebeests");
+    assert_eq!(e.plural_noun::<i32>("guinea-fowl", None), "guinea-fowls");
+    assert_eq!(e.plural_noun::<i32>("guinea fowl", None), "guinea fowls");
+    assert_eq!(e.plural_noun::<i32>("water-fowl", None), "water-fowls");
+    assert_eq!(e.plural_noun::<i32>("tuna", None), "tuna");
+    assert_eq!(e.plural_noun::<i32>("cod", None), "cod");
+
+    e.classical_dict.insert("herd".to_string(), true);
+    assert_eq!(e.plural_noun::<i32>("bison", None), "bison");
+    assert_eq!(e.plural_noun::<i32>("buffalo", None), "buffalo");
+    assert_eq!(e.plural_noun::<i32>("haddock", None), "haddock");
+    assert_eq!(e.plural_noun::<i32>("guinea-fowl", None), "guinea-fowl");
+    assert_eq!(e.plural_noun::<i32>("guinea fowl", None), "guinea fowl");
+    assert_eq!(e.plural_noun::<i32>("water fowl", None), "water fowl");
+    assert_eq!(e.plural_noun::<i32>("salmon", None), "salmon");
+    assert_eq!(
+        e.singular_noun::<i32>("bison", None, None),
+        Some("bison".to_string())
+    );
+}
+
+#[test]
+fn test_count() {
+    let mut e = Engine::new();
+    assert_eq!(e.get_count(Some(1u64)), 1);
+    assert_eq!(e.get_count(Some(7u8)), 7);
+    assert_eq!(e.get_count(Some(u64::MAX)), 2);
+    assert_eq!(e.get_count(Some(-3i64)), -3);
+    assert_eq!(e.get_count(Some(1.0f64)), 2);
+    assert_eq!(e.get_count(Some(0.5f32)), 2);
+    assert_eq!(e.get_count(Some("1.50")), 2);
+    assert_eq!(e.get_count(Some("1.0")), 2);
+    assert_eq!(e.plural_noun("file", Some(1u64)), "file");
+    assert_eq!(e.plural_noun("file", Some(1.0f64)), "files");
+    assert_eq!(e.plural_noun("dollar", Some("1.50")), "dollars");
+
+    e.decimal_one_is_singular = true;
+    assert_eq!(e.get_count(Some(1.0f64)), 1);
+    assert_eq!(e.get_count(Some("1.00")), 1);
+    assert_eq!(e.get_count(Some("1.50")), 2);
+    assert_eq!(e.plural_noun("file", Some(1.0f32)), "file");
+    assert_eq!(e.plural_verb("is", Some(1.0f64)), "is");
+}
+
+#[test]
+fn test_plural_category() {
+    let e = Engine::new();
+    assert_eq!(e.plural_category(1), PluralCategory::One);
+    assert_eq!(e.plural_category(-1i64), PluralCategory::One);
+    assert_eq!(e.plural_category(0u8), PluralCategory::Other);
+    assert_eq!(e.plural_category(2), PluralCategory::Other);
+    assert_eq!(e.plural_category("1"), PluralCategory::One);
+    assert_eq!(e.plural_category("1.0"), PluralCategory::Other);
+    assert_eq!(e.plural_category("1.50"), PluralCategory::Other);
+    assert_eq!(e.plural_category(1.0f64), PluralCategory::Other);
+    assert_eq!(e.plural_category("an"), PluralCategory::One);
+    assert_eq!(e.plural_category("some"), PluralCategory::Other);
+    assert_eq!(e.plural_category(PluralCategory::Few), PluralCategory::Few);
+
+    assert_eq!(e.plural_noun("cat", Some(PluralCategory::One)), "cat");
+    assert_eq!(e.plural_noun("cat", Some(PluralCategory::Other)), "cats");
+    assert_eq!(e.plural_noun("cat", Some(PluralCategory::Zero)), "cats");
+    assert_eq!(e.plural_verb("is", Some(PluralCategory::One)), "is");
+    assert_eq!(
+        e.singular_noun("cats", Some(PluralCategory::One), None),
+        Some("cat".to_string())
+    );
+}
+
+#[test]
+fn test_number_to_words() {
+    let mut e = Engine::new();
+    let opts = NumberToWordsOptions::default();
+    let cases = [
+        ("0", "zero"),
+        ("1", "one"),
+        ("21", "twenty-one"),
+        ("101", "one hundred and one"),
+        ("1005", "one thousand and five"),
+        ("1234", "one thousand, two hundred and thirty-four"),
+        ("1000000", "one million"),
+        ("1001000", "one million, one thousand"),
+        ("-15", "minus fifteen"),
+        ("3.14", "three point one four"),
+        (".5", "point five"),
+        ("21st", "twenty-first"),
+    ];
+    for (num, words) in cases {
+        assert_eq!(e.number_to_words(num, &opts), Ok(words.to_string()));
+    }
+    assert_eq!(e.number_to_words(1234u64, &opts).unwrap(), cases[5].1);
+    assert_eq!(e.number_to_words("1,234", &opts).unwrap(), cases[5].1);
+    for bad in ["abc", "1e3", "--5", "1,00", "1.2.3", "th", "", "2.5th"] {
+        assert_eq!(
+            e.number_to_words(bad, &opts),
+            Err(InflectError::BadNumValue(bad.to_string()))
+        );
+    }
+    assert!(e.number_to_words(f64::NAN, &opts).is_err());
+    assert!(e.number_to_words(f64::INFINITY, &opts).is_err());
+
+    let opts = NumberToWordsOptions {
+        group: 2,
+        ..Default::default()
+    };
+    assert_eq!(
+        e.number_to_words("12345", &opts),
+        Ok("twelve, thirty-four, five".to_string())
+    );
+    let opts = NumberToWordsOptions {
+        comma: "".to_string(),
+        andword: "".to_string(),
+        ..Default::default()
+    };
+    assert_eq!(
+        e.number_to_words(1234, &opts),
+        Ok("one thousand two hundred thirty-four".to_string())
+    );
+    let opts = NumberToWordsOptions {
+        threshold: Some(10.0),
+        ..Default::default()
+    };
+    assert_eq!(e.number_to_words(9, &opts), Ok("nine".to_string()));
+    assert_eq!(
+        e.number_to_words(1234567, &opts),
+        Ok("1,234,567".to_string())
+    );
+    let opts = NumberToWordsOptions {
+        group: 4,
+        ..Default::default()
+    };
+    assert_eq!(
+        e.number_to_words(1, &opts),
+        Err(InflectError::BadChunkingOption)
+    );
+    assert_eq!(
+        e.number_to_words_list(-1234.5, &NumberToWordsOptions::default()),
+        Ok(vec![
+            "minus".to_string(),
+            "one thousand".to_string(),
+            "two hundred and thirty-four".to_string(),
+            "point".to_string(),
+            "five".to_string()
+        ])
+    );
+}
+
+#[test]
+fn test_number_to_words_large() {
+    let mut e = Engine::new();
+    let opts = NumberToWordsOptions::default();
+    let words = e.number_to_words(u128::MAX, &opts).unwrap();
+    assert!(words.starts_with("three hundred and forty undecillion, "));
+    assert!(words.ends_with("two hundred and eleven thousand, four hundred and fifty-five"));
+    let words = e.number_to_words(i128::MIN, &opts).unwrap();
+    assert!(words.starts_with("minus one hundred and seventy undecillion, "));
+
+    let googol = format!("1{}", "0".repeat(100));
+    assert_eq!(
+        e.number_to_words(googol.as_str(), &opts),
+        Ok("ten duotrigintillion".to_string())
+    );
+    let centillion = format!("1{}", "0".repeat(303));
+    assert_eq!(
+        e.number_to_words(centillion.as_str(), &opts),
+        Ok("one centillion".to_string())
+    );
+    let too_big = format!("1{}", "0".repeat(306));
+    assert_eq!(
+        e.number_to_words(too_big.as_str(), &opts),
+        Err(InflectError::NumOutOfRange)
+    );
+    let opts = NumberToWordsOptions {
+        scale_overflow: ScaleOverflow::Compound,
+        ..Default::default()
+    };
+    assert_eq!(
+        e.number_to_words(too_big.as_str(), &opts),
+        Ok("one thousand centillion".to_string())
+    );
+}
+
+#[test]
+fn test_numbering_system() {
+    let mut e = Engine::new();
+    let long = NumberToWordsOptions {
+        numbering: NumberingSystem::LongScale,
+        ..Default::default()
+    };
+    assert_eq!(
+        e.number_to_words(2_000_000_000u64, &long),
+        Ok("two milliard".to_string())
+    );
+    assert_eq!(
+        e.number_to_words(3_000_001_000_000u64, &long),
+        Ok("three billion, one million".to_string())
+    );
+    assert_eq!(
+        e.number_to_words(1234, &long),
+        Ok("one thousand, two hundred and thirty-four".to_string())
+    );
+
+    let indian = NumberToWordsOptions {
+        numbering: NumberingSystem::Indian,
+        ..Default::default()
+    };
+    let cases = [
+        (100_000u64, "one lakh"),
+        (
+            1_234_567,
+            "twelve lakh, thirty-four thousand, five hundred and sixty-seven",
+        ),
+        (10_000_000, "one crore"),
+        (120_005_000, "twelve crore, five thousand"),
+        (1_000_000_000_000, "one lakh crore"),
+    ];
+    for (num, words) in cases {
+        assert_eq!(e.number_to_words(num, &indian), Ok(words.to_string()));
+    }
+    let opts = NumberToWordsOptions {
+        comma: "".to_string(),
+        ..indian.clone()
+    };
+    assert_eq!(
+        e.number_to_words(1_234_567, &opts),
+        Ok("twelve lakh thirty-four thousand five hundred and sixty-seven".to_string())
+    );
+    let opts = NumberToWordsOptions {
+        threshold: Some(1000.0),
+        ..indian.clone()
+    };
+    assert_eq!(
+        e.number_to_words(12_345_678, &opts),
+        Ok("1,23,45,678".to_string())
+    );
+    assert_eq!(e.number_to_words(1234, &opts), Ok("1,234".to_string()));
+    let style = NumberStyle {
+        number: indian.clone(),
+        ..NumberStyle::ap()
+    };
+    assert_eq!(
+        e.format_number(-1_234_567, &style, Position::Inline),
+        Ok("-12,34,567".to_string())
+    );
+    let opts = NumberToWordsOptions { group: 2, ..indian };
+    assert_eq!(
+        e.number_to_words(1234, &opts),
+        Ok("twelve, thirty-four".to_string())
+    );
+}
+
+#[test]
+fn test_decimal_style() {
+    let mut e = Engine::new();
+    let opts = NumberToWordsOptions {
+        decimal_style: DecimalStyle::PlaceValue,
+        ..Default::default()
+    };
+    let cases = [
+        ("3.14", "three and fourteen hundredths"),
+        ("0.5", "five tenths"),
+        (".001", "one thousandth"),
+        ("-2.25", "minus two and twenty-five hundredths"),
+        ("1.0001", "one and one ten-thousandth"),
+        ("0.000002", "two millionths"),
+        (
+            "1234.5",
+            "one thousand, two hundred and thirty-four and five tenths",
+        ),
+        ("7", "seven"),
+    ];
+    for (num, words) in cases {
+        assert_eq!(e.number_to_words(num, &opts), Ok(words.to_string()));
+    }
+    assert_eq!(
+        e.number_to_words(2.75f64, &opts),
+        Ok("two and seventy-five hundredths".to_string())
+    );
+    assert_eq!(
+        e.number_to_words_list("3.14", &opts),
+        Ok(vec![
+            "three".to_string(),
+            "and".to_string(),
+            "fourteen hundredths".to_string()
+        ])
+    );
+
+    let opts = NumberToWordsOptions {
+        decimal: Some("dot".to_string()),
+        trim_trailing_zeros: true,
+        ..Default::default()
+    };
+    assert_eq!(
+        e.number_to_words("2.50", &opts),
+        Ok("two dot five".to_string())
+    );
+    assert_eq!(e.number_to_words("3.00", &opts), Ok("three".to_string()));
+    assert_eq!(
+        e.number_to_words("2.50", &NumberToWordsOptions::default()),
+        Ok("two point five zero".to_string())
+    );
+    let opts = NumberToWordsOptions {
+        decimal_style: DecimalStyle::PlaceValue,
+        trim_trailing_zeros: true,
+        ..Default::default()
+    };
+    assert_eq!(
+        e.number_to_words("0.500", &opts),
+        Ok("five tenths".to_string())
+    );
+}
+
+#[test]
+fn test_verbalize_numbers() {
+    let mut e = Engine::new();
+    let opts = VerbalizeOptions::default();
+    let cases = [
+        ("I have 3 cats.", "I have three cats."),
+        ("She came 3rd, not 21st!", "She came third, not twenty-first!"),
+        ("It is -4.5 degrees", "It is minus four point five degrees"),
+        ("Prices rose 12%.", "Prices rose twelve percent."),
+        (
+            "It costs $5.50 (was £2)",
+            "It costs five dollars and fifty cents (was two pounds)",
+        ),
+        (
+            "Population: 1,234,567",
+            "Population: one million, two hundred and thirty-four thousand, five hundred and sixty-seven",
+        ),
+        ("Born in 1984.", "Born in nineteen eighty-four."),
+        ("On March 3, 1999", "On March three, nineteen ninety-nine"),
+        ("We sold 1984 copies", "We sold one thousand, nine hundred and eighty-four copies"),
+        ("COVID-19 and mp3 v1.2.3", "COVID-nineteen and mp3 v1.2.3"),
+        ("pages 10-20", "pages 10-20"),
+        ("call 555-1234", "call 555-1234"),
+        ("1/2 cup", "1/2 cup"),
+        ("a 5-year plan", "a five-year plan"),
+        ("no numbers here", "no numbers here"),
+        ("the 2th and 11st", "the 2th and 11st"),
+        ("the 12th", "the twelfth"),
+        ("1,00 points", "1,00 points"),
+        ("1234,567", "1234,567"),
+    ];
+    for (text, words) in cases {
+        assert_eq!(e.verbalize_numbers(text, &opts).text, words);
+    }
+
+    let source = "Take 2 of 15";
+    let verbalized = e.verbalize_numbers(source, &opts);
+    assert_eq!(verbalized.text, "Take two of fifteen");
+    assert_eq!(verbalized.spans.len(), 2);
+    for span in &verbalized.spans {
+        let words = &verbalized.text[span.output.clone()];
+        let number = &source[span.source.clone()];
+        assert_eq!(
+            e.number_to_words(number, &NumberToWordsOptions::default()),
+            Ok(words.to_string())
+        );
+    }
+    assert_eq!(verbalized.spans[1].source, 10..12);
+    assert_eq!(verbalized.spans[1].output, 12..19);
+
+    let opts = VerbalizeOptions {
+        years: false,
+        ..Default::default()
+    };
+    assert_eq!(
+        e.verbalize_numbers("in 2005", &opts).text,
+        "in two thousand and five"
+    );
+}
+
+#[test]
+fn test_percent_and_ratio() {
+    let mut e = Engine::new();
+    let opts = PercentOptions::default();
+    assert_eq!(
+        e.percent_to_words("45%", &opts),
+        Ok("forty-five percent".to_string())
+    );
+    assert_eq!(
+        e.percent_to_words(45, &opts),
+        Ok("forty-five percent".to_string())
+    );
+    assert_eq!(
+        e.percent_to_words("0.5%", &opts),
+        Ok("zero point five percent".to_string())
+    );
+    assert_eq!(
+        e.percent_to_words("-2.5", &opts),
+        Ok("minus two point five percent".to_string())
+    );
+    assert!(e.percent_to_words("lots%", &opts).is_err());
+    for bad in ["NaN", "inf%", "1e3%", "-"] {
+        assert!(e.percent_to_words(bad, &opts).is_err());
+    }
+    let opts = PercentOptions {
+        word: PercentWord::PerCent,
+        fractions: true,
+        ..Default::default()
+    };
+    assert_eq!(
+        e.percent_to_words("0.5%", &opts),
+        Ok("half a per cent".to_string())
+    );
+    assert_eq!(
+        e.percent_to_words(0.25, &opts),
+        Ok("a quarter of a per cent".to_string())
+    );
+    assert_eq!(
+        e.percent_to_words("12%", &opts),
+        Ok("twelve per cent".to_string())
+    );
+
+    let number = NumberToWordsOptions::default();
+    assert_eq!(
+        e.ratio_to_words("3:1", &number),
+        Ok("three to one".to_string())
+    );
+    assert_eq!(
+        e.ratio_to_words("16 : 9", &number),
+        Ok("sixteen to nine".to_string())
+    );
+    assert_eq!(
+        e.ratio_to_words("2:1:1", &number),
+        Ok("two to one to one".to_string())
+    );
+    assert!(e.ratio_to_words("3", &number).is_err());
+    assert!(e.ratio_to_words("3:", &number).is_err());
+    assert!(e.ratio_to_words("a:b", &number).is_err());
+    assert!(e.ratio_to_words("inf:1", &number).is_err());
+    assert!(e.ratio_to_words("1e3:1", &number).is_err());
+    assert!(e.ratio_to_words("1st:2", &number).is_err());
+    assert_eq!(
+        e.ratio_to_words("1,000:1", &number),
+        Ok("one thousand to one".to_string())
+    );
+    assert_eq!(
+        e.percent_to_words("1,000%", &PercentOptions::default()),
+        Ok("one thousand percent".to_string())
+    );
+    assert!(e
+        .percent_to_words("1,00%", &PercentOptions::default())
+        .is_err());
+
+    let opts = VerbalizeOptions::default();
+    assert_eq!(
+        e.verbalize_numbers("Odds are 3:1, up 0.5%", &opts).text,
+        "Odds are three to one, up zero point five percent"
+    );
+    assert_eq!(
+        e.verbalize_numbers("Shown in 16:9 at 10:45, mixed 2:1:1.", &opts)
+            .text,
+        "Shown in sixteen to nine at ten forty-five, mixed two to one to one."
+    );
+    assert_eq!(
+        e.verbalize_numbers("Mix 3:1 with 10:30, not 25:99", &opts)
+            .text,
+        "Mix three to one with ten thirty, not 25:99"
+    );
+    let opts = VerbalizeOptions {
+        percent: PercentOptions {
+            word: PercentWord::PerCent,
+            fractions: true,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    assert_eq!(
+        e.verbalize_numbers("Rates rose 0.5%.", &opts).text,
+        "Rates rose half a per cent."
+    );
+}
+
+#[test]
+fn test_words_to_number() {
+    let mut e = Engine::new();
+    assert_eq!(e.words_to_number("twenty-one"), Ok(Number::Int(21)));
+    assert_eq!(
+        e.words_to_number("one hundred and five"),
+        Ok(Number::Int(105))
+    );
+    assert_eq!(e.words_to_number("a hundred"), Ok(Number::Int(100)));
+    assert_eq!(
+        e.words_to_number("two million four thousand"),
+        Ok(Number::Int(2_004_000))
+    );
+    assert_eq!(
+        e.words_to_number("two point seven five"),
+        Ok(Number::Float(2.75))
+    );
+    assert_eq!(e.words_to_number("minus seven"), Ok(Number::Int(-7)));
+    assert_eq!(e.words_to_number("oh"), Ok(Number::Int(0)));
+    assert_eq!(e.words_to_number("nil"), Ok(Number::Int(0)));
+    assert_eq!(e.words_to_number("point oh five"), Ok(Number::Float(0.05)));
+    assert!(e.words_to_number("twenty thirty").is_err());
+    assert!(e.words_to_number("one two").is_err());
+    assert!(e.words_to_number("and five").is_err());
+    assert!(e.words_to_number("").is_err());
+    assert!(e.words_to_number("one thousand one thousand").is_err());
+    assert!(e.words_to_number("one thousand two million").is_err());
+    assert!(e.words_to_number("one point").is_err());
+    assert!(e.words_to_number("point").is_err());
+    assert_eq!(
+        e.words_to_number("one centillion"),
+        Err(InflectError::NumOutOfRange)
+    );
+
+    let opts = NumberToWordsOptions::default();
+    for n in (-300..1100)
+        .step_by(3)
+        .chain([12_345, 1_000_001, 987_654_321, i64::MAX as i128 - 1])
+    {
+        let words = e.number_to_words(n, &opts).unwrap();
+        assert_eq!(e.words_to_number(&words), Ok(Number::Int(n)), "{}", words);
+    }
+    for n in ["3.14", "-0.5", "12.05"] {
+        let words = e.number_to_words(n, &opts).unwrap();
+        assert_eq!(
+            e.words_to_number(&words),
+            Ok(Number::Float(n.parse().unwrap()))
+        );
+    }
+}
+
+#[test]
+fn test_year_to_words() {
+    let mut e = Engine::new();
+    let opts = YearToWordsOptions::default();
+    let cases = [
+        (1984, "nineteen eighty-four"),
+        (1900, "nineteen hundred"),
+        (1101, "eleven oh-one"),
+        (2000, "two thousand"),
+        (2005, "two thousand and five"),
+        (2019, "twenty nineteen"),
+        (476, "four seventy-six"),
+        (800, "eight hundred"),
+        (66, "sixty-six"),
+        (-44, "forty-four BC"),
+        (12345, "twelve thousand, three hundred and forty-five"),
+    ];
+    for (year, words) in cases {
+        assert_eq!(e.year_to_words(year, &opts), Ok(words.to_string()));
+    }
+    assert!(e.year_to_words(0, &opts).is_err());
+
+    let opts = YearToWordsOptions {
+        millennium: MillenniumStyle::Pairs,
+        era: Era::BceCe,
+        ..Default::default()
+    };
+    assert_eq!(
+        e.year_to_words(2005, &opts),
+        Ok("twenty oh-five".to_string())
+    );
+    assert_eq!(
+        e.year_to_words(-1200, &opts),
+        Ok("twelve hundred BCE".to_string())
+    );
+    let opts = YearToWordsOptions {
+        andword: "".to_string(),
+        ..Default::default()
+    };
+    assert_eq!(
+        e.year_to_words(2005, &opts),
+        Ok("two thousand five".to_string())
+    );
+}
+
+#[test]
+fn test_currency_to_words() {
+    let mut e = Engine::new();
+    let usd = e.currency("usd").unwrap();
+    let opts = CurrencyOptions::default();
+    let cases = [
+        (
+            "1250.07",
+            "one thousand two hundred and fifty dollars and seven cents",
+        ),
+        ("1", "one dollar"),
+        ("0.01", "one cent"),
+        ("2.005", "two dollars and one cent"),
+        ("1,999.999", "two thousand dollars"),
+        ("-3.5", "minus three dollars and fifty cents"),
+        ("0", "zero dollars"),
+    ];
+    for (amount, words) in cases {
+        assert_eq!(
+            e.currency_to_words(amount, &usd, &opts),
+            Ok(words.to_string())
+        );
+    }
+    assert!(e.currency_to_words("1.2.3", &usd, &opts).is_err());
+
+    let gbp = e.currency("GBP").unwrap();
+    assert_eq!(
+        e.currency_to_words(1.01, &gbp, &opts),
+        Ok("one pound and one penny".to_string())
+    );
+    assert_eq!(
+        e.currency_to_words(2.02, &gbp, &opts),
+        Ok("two pounds and two pence".to_string())
+    );
+    let jpy = e.currency("JPY").unwrap();
+    assert_eq!(
+        e.currency_to_words(500.4, &jpy, &opts),
+        Ok("five hundred yen".to_string())
+    );
+
+    let cheque = CurrencyOptions {
+        minor_style: MinorStyle::Fraction,
+        ..Default::default()
+    };
+    assert_eq!(
+        e.currency_to_words("1250.07", &usd, &cheque),
+        Ok("one thousand two hundred and fifty dollars and 07/100".to_string())
+    );
+    assert_eq!(
+        e.currency_to_words(5, &usd, &cheque),
+        Ok("five dollars and 00/100".to_string())
+    );
+
+    let opts = CurrencyOptions {
+        rounding: Rounding::HalfEven,
+        ..Default::default()
+    };
+    assert_eq!(
+        e.currency_to_words("0.125", &usd, &opts),
+        Ok("twelve cents".to_string())
+    );
+    let opts = CurrencyOptions {
+        rounding: Rounding::Down,
+        ..Default::default()
+    };
+    assert_eq!(
+        e.currency_to_words("0.129", &usd, &opts),
+        Ok("twelve cents".to_string())
+    );
+
+    e.currencies
+        .register(Currency::new("BHD", "dinar", Some("fils"), 3).with_plurals(None, Some("fils")));
+    let bhd = e.currency("BHD").unwrap();
+    assert_eq!(
+        e.currency_to_words("1.5", &bhd, &CurrencyOptions::default()),
+        Ok("one dinar and five hundred fils".to_string())
+    );
+}
+
+#[test]
+fn test_fraction_to_words() {
+    let mut e = Engine::new();
+    let opts = FractionToWordsOptions::default();
+    let cases = [
+        ((3, 4), "three quarters"),
+        ((1, 2), "one half"),
+        ((3, 2), "three halves"),
+        ((1, 3), "one third"),
+        ((7, 64), "seven sixty-fourths"),
+        ((1, 100), "one hundredth"),
+        ((3, 1000), "three thousandths"),
+        ((-2, 5), "minus two fifths"),
+        ((6, 1), "six"),
+    ];
+    for ((n, d), words) in cases {
+        assert_eq!(e.fraction_to_words(n, d, &opts), Ok(words.to_string()));
+    }
+    assert!(e.fraction_to_words(1, 0, &opts).is_err());
+
+    let opts = FractionToWordsOptions {
+        fourths: FourthsStyle::Fourths,
+    };
+    assert_eq!(
+        e.fraction_to_words(3, 4, &opts),
+        Ok("three fourths".to_string())
+    );
+    assert_eq!(
+        e.fraction_to_words(1, 4, &opts),
+        Ok("one fourth".to_string())
+    );
+
+    let opts = FractionToWordsOptions::default();
+    assert_eq!(
+        e.fraction_str_to_words("2 1/2", &opts),
+        Ok("two and a half".to_string())
+    );
+    assert_eq!(
+        e.fraction_str_to_words("-1 3/4", &opts),
+        Ok("minus one and three quarters".to_string())
+    );
+    assert_eq!(
+        e.fraction_str_to_words(" 3/4 ", &opts),
+        Ok("three quarters".to_string())
+    );
+    assert_eq!(
+        e.fraction_str_to_words("  7 / 8 ", &opts),
+        Ok("seven eighths".to_string())
+    );
+    assert_eq!(
+        e.fraction_str_to_words("2 1 / 2", &opts),
+        Ok("two and a half".to_string())
+    );
+    assert_eq!(e.fraction_str_to_words("5", &opts), Ok("five".to_string()));
+    assert!(e.fraction_str_to_words("1 2 3/4", &opts).is_err());
+    assert!(e.fraction_str_to_words("3/", &opts).is_err());
+    assert!(e.fraction_str_to_words("a/b", &opts).is_err());
+    assert_eq!(
+        "2 1/2".parse::<Fraction>(),
+        Ok(Fraction {
+            negative: false,
+            whole: 2,
+            numerator: 1,
+            denominator: 2
+        })
+    );
+}
+
+#[test]
+fn test_time_to_words() {
+    let mut e = Engine::new();
+    let cases = [
+        (
+            (10, 45),
+            "ten forty-five",
+            "a quarter to eleven",
+            "ten forty-five hours",
+        ),
+        ((10, 30), "ten thirty", "half past ten", "ten thirty hours"),
+        (
+            (15, 5),
+            "three oh-five",
+            "five past three",
+            "fifteen oh-five hours",
+        ),
+        (
+            (14, 0),
+            "two o'clock",
+            "two o'clock",
+            "fourteen hundred hours",
+        ),
+        (
+            (9, 7),
+            "nine oh-seven",
+            "seven minutes past nine",
+            "oh-nine oh-seven hours",
+        ),
+        ((12, 0), "noon", "noon", "twelve hundred hours"),
+        ((0, 0), "midnight", "midnight", "zero hundred hours"),
+        (
+            (23, 45),
+            "eleven forty-five",
+            "a quarter to midnight",
+            "twenty-three forty-five hours",
+        ),
+        (
+            (0, 30),
+            "twelve thirty",
+            "half past midnight",
+            "zero thirty hours",
+        ),
+        (
+            (11, 59),
+            "eleven fifty-nine",
+            "one minute to noon",
+            "eleven fifty-nine hours",
+        ),
+    ];
+    for ((h, m), digital, traditional, military) in cases {
+        assert_eq!(
+            e.time_to_words(h, m, TimeStyle::Digital),
+            Ok(digital.to_string())
+        );
+        assert_eq!(
+            e.time_to_words(h, m, TimeStyle::Traditional),
+            Ok(traditional.to_string())
+        );
+        assert_eq!(
+            e.time_to_words(h, m, TimeStyle::Military),
+            Ok(military.to_string())
+        );
+    }
+    assert!(e.time_to_words(24, 0, TimeStyle::Digital).is_err());
+    assert!(e.time_to_words(10, 60, TimeStyle::Digital).is_err());
+}
+
+#[test]
+fn test_join() {
+    let e = Engine::new();
+    let opts = JoinOptions::default();
+    assert_eq!(e.join::<&str>(&[], &opts), "");
+    assert_eq!(e.join(&["a"], &opts), "a");
+    assert_eq!(e.join(&["a", "b"], &opts), "a and b");
+    assert_eq!(e.join(&["a", "b", "c"], &opts), "a, b, and c");
+    assert_eq!(e.join(&["a, b", "c", "d"], &opts), "a, b; c; and d");
+    let opts = JoinOptions {
+        final_sep: Some("".to_string()),
+        conj: "or".to_string(),
+        ..Default::default()
+    };
+    assert_eq!(e.join(&["a", "b", "c"], &opts), "a, b or c");
+    let opts = JoinOptions {
+        sep: Some("/".to_string()),
+        sep_spaced: false,
+        conj: "".to_string(),
+        ..Default::default()
+    };
+    assert_eq!(e.join(&["a", "b", "c"], &opts), "a/b/ c");
+}
+
+#[test]
+fn test_duration_to_words() {
+    use std::time::Duration;
+    let mut e = Engine::new();
+    let opts = DurationOptions::default();
+    let cases = [
+        (7290, "2 hours, 1 minute and 30 seconds"),
+        (60, "1 minute"),
+        (0, "0 seconds"),
+        (90061, "1 day, 1 hour, 1 minute and 1 second"),
+        (864000, "10 days"),
+    ];
+    for (secs, words) in cases {
+        assert_eq!(
+            e.duration_to_words(Duration::from_secs(secs), &opts),
+            Ok(words.to_string())
+        );
+    }
+    assert_eq!(
+        e.duration_to_words(Duration::from_millis(1600), &opts),
+        Ok("2 seconds".to_string())
+    );
+
+    let opts = DurationOptions {
+        words: true,
+        max_parts: Some(2),
+        ..Default::default()
+    };
+    assert_eq!(
+        e.duration_to_words(Duration::from_secs(7290), &opts),
+        Ok("two hours and two minutes".to_string())
+    );
+    let opts = DurationOptions {
+        rounding: Rounding::Down,
+        ..opts
+    };
+    assert_eq!(
+        e.duration_to_words(Duration::from_secs(7290), &opts),
+        Ok("two hours and one minute".to_string())
+    );
+    let opts = DurationOptions {
+        max_parts: Some(1),
+        ..Default::default()
+    };
+    assert_eq!(
+        e.duration_to_words(Duration::from_secs(3599), &opts),
+        Ok("1 hour".to_string())
+    );
+
+    let opts = DurationOptions {
+        largest: TimeUnit::Year,
+        relative: Some(Relative::Past),
+        ..Default::default()
+    };
+    assert_eq!(
+        e.duration_to_words(Duration::from_secs(86400 * 380), &opts),
+        Ok("1 year, 2 weeks and 1 day ago".to_string())
+    );
+    let opts = DurationOptions {
+        largest: TimeUnit::Hour,
+        smallest: TimeUnit::Minute,
+        relative: Some(Relative::Future),
+        ..Default::default()
+    };
+    assert_eq!(
+        e.duration_to_words(Duration::from_secs(86400 * 3 + 20), &opts),
+        Ok("in 72 hours".to_string())
+    );
+    let opts = DurationOptions {
+        smallest: TimeUnit::Millisecond,
+        ..Default::default()
+    };
+    assert_eq!(
+        e.duration_to_words(Duration::from_millis(1001), &opts),
+        Ok("1 second and 1 millisecond".to_string())
+    );
+}
+
+#[test]
+fn test_date_to_words() {
+    let mut e = Engine::new();
+    let cases = [
+        (DateStyle::Us, "October 18th, 2026"),
+        (
+            DateStyle::UsSpelled,
+            "October eighteenth, twenty twenty-six",
+        ),
+        (DateStyle::Uk, "18 October 2026"),
+        (
+            DateStyle::UkSpelled,
+            "the eighteenth of October, twenty twenty-six",
+        ),
+    ];
+    for (style, words) in cases {
+        assert_eq!(e.date_to_words(2026, 10, 18, style), Ok(words.to_string()));
+    }
+    assert_eq!(
+        e.date_to_words(2001, 3, 22, DateStyle::Us),
+        Ok("March 22nd, 2001".to_string())
+    );
+    assert_eq!(
+        e.date_to_words(2005, 1, 1, DateStyle::UkSpelled),
+        Ok("the first of January, two thousand and five".to_string())
+    );
+    assert_eq!(
+        e.date_to_words(1999, 12, 31, DateStyle::UsSpelled),
+        Ok("December thirty-first, nineteen ninety-nine".to_string())
+    );
+    assert_eq!(
+        e.date_to_words(-44, 3, 15, DateStyle::Uk),
+        Ok("15 March 44 BC".to_string())
+    );
+    assert_eq!(
+        e.date_to_words(2024, 2, 29, DateStyle::Us),
+        Ok("February 29th, 2024".to_string())
+    );
+    assert!(e.date_to_words(2023, 2, 29, DateStyle::Us).is_err());
+    assert!(e.date_to_words(1900, 2, 29, DateStyle::Us).is_err());
+    assert!(e.date_to_words(2026, 13, 1, DateStyle::Us).is_err());
+    assert!(e.date_to_words(2026, 4, 31, DateStyle::Us).is_err());
+    assert!(e.date_to_words(0, 1, 1, DateStyle::Us).is_err());
+}
+
+#[test]
+fn test_range() {
+    let mut e = Engine::new();
+    let digits = RangeStyle::default();
+    let words = RangeStyle {
+        words: true,
+        ..Default::default()
+    };
+    let between = RangeStyle {
+        form: RangeForm::Between,
+        words: true,
+        ..Default::default()
+    };
+    assert_eq!(
+        e.range(Some(1), Some(3), "result", digits),
+        Ok("1–3 results".to_string())
+    );
+    assert_eq!(
+        e.range(Some(1), Some(3), "result", words),
+        Ok("one to three results".to_string())
+    );
+    assert_eq!(
+        e.range(Some(3), Some(5), "file", between),
+        Ok("between three and five files".to_string())
+    );
+    assert_eq!(
+        e.range(None, Some(10), "item", digits),
+        Ok("up to 10 items".to_string())
+    );
+    assert_eq!(
+        e.range(Some(100), None, "user", words),
+        Ok("more than one hundred users".to_string())
+    );
+    assert_eq!(
+        e.range(Some(1), None, "user", words),
+        Ok("more than one user".to_string())
+    );
+    assert_eq!(
+        e.range(Some(2), Some(2), "child", words),
+        Ok("two children".to_string())
+    );
+    assert_eq!(
+        e.range(Some(0), Some(1), "file", digits),
+        Ok("0–1 file".to_string())
+    );
+    let plural = RangeStyle {
+        agreement: RangeAgreement::Plural,
+        ..Default::default()
+    };
+    assert_eq!(
+        e.range(Some(0), Some(1), "file", plural),
+        Ok("0–1 files".to_string())
+    );
+    assert!(e.range(Some(5), Some(3), "file", digits).is_err());
+    assert!(e.range(None, None, "file", digits).is_err());
+}
+
+#[test]
+fn test_approx_number() {
+    let e = Engine::new();
+    let opts = ApproxOptions::default();
+    let cases = [
+        (1_234_567, "1.2 million"),
+        (1_000_000, "1 million"),
+        (999_999, "1 million"),
+        (1_500, "1.5 thousand"),
+        (950, "950"),
+        (42, "42"),
+        (3_987_654_321, "4 billion"),
+    ];
+    for (n, words) in cases {
+        assert_eq!(e.approx_number(n, &opts), words);
+    }
+    assert_eq!(
+        e.approx_number(u128::MAX, &opts),
+        "340 undecillion".to_string()
+    );
+
+    let opts = ApproxOptions {
+        noun: Some("user".to_string()),
+        ..Default::default()
+    };
+    assert_eq!(e.approx_number(1_000_000, &opts), "1 million users");
+    assert_eq!(e.approx_number(1, &opts), "1 user");
+    let opts = ApproxOptions {
+        noun: Some("file".to_string()),
+        hedge: Hedge::About,
+        ..Default::default()
+    };
+    assert_eq!(e.approx_number(1_549, &opts), "about 1.5 thousand files");
+
+    let auto = ApproxOptions {
+        hedge: Hedge::Auto,
+        significant: 1,
+        ..Default::default()
+    };
+    assert_eq!(e.approx_number(3_200, &auto), "over 3 thousand");
+    assert_eq!(e.approx_number(3_870_000_000, &auto), "nearly 4 billion");
+    assert_eq!(e.approx_number(2_000, &auto), "2 thousand");
+    let down = ApproxOptions {
+        rounding: Rounding::Down,
+        ..auto
+    };
+    assert_eq!(e.approx_number(3_870_000_000, &down), "over 3 billion");
+
+    let vague = ApproxOptions {
+        hedge: Hedge::Vague,
+        noun: Some("user".to_string()),
+        ..Default::default()
+    };
+    assert_eq!(e.approx_number(300, &vague), "a few hundred users");
+    assert_eq!(e.approx_number(7_000, &vague), "several thousand users");
+    assert_eq!(
+        e.approx_number(45_000_000, &vague),
+        "tens of millions of users"
+    );
+    assert_eq!(e.approx_number(120, &vague), "about a hundred users");
+}
+
+#[test]
+fn test_format_number() {
+    let mut e = Engine::new();
+    let ap = NumberStyle::ap();
+    let chicago = NumberStyle::chicago();
+    let inline = Position::Inline;
+    let cases = [
+        (7, "seven", "seven"),
+        (9, "nine", "nine"),
+        (10, "10", "ten"),
+        (42, "42", "forty-two"),
+        (100, "100", "one hundred"),
+        (101, "101", "101"),
+        (300, "300", "three hundred"),
+        (12_500, "12,500", "12,500"),
+        (35_000, "35,000", "thirty-five thousand"),
+        (2_000_000, "2,000,000", "two million"),
+    ];
+    for (n, ap_form, chicago_form) in cases {
+        assert_eq!(e.format_number(n, &ap, inline), Ok(ap_form.to_string()));
+        assert_eq!(
+            e.format_number(n, &chicago, inline),
+            Ok(chicago_form.to_string())
+        );
+    }
+    assert_eq!(
+        e.format_number("3.5", &chicago, inline),
+        Ok("3.5".to_string())
+    );
+    assert_eq!(e.format_number(-15, &ap, inline), Ok("-15".to_string()));
+    assert_eq!(
+        e.format_number(u128::MAX, &chicago, inline)
+            .map(|s| s.len()),
+        Ok(51)
+    );
+    assert!(e.format_number("abc", &ap, inline).is_err());
+    for bad in [
+        "1e3", "NaN", "inf", "infinity", "1.2.3", "--5", "1,2,3", "+-5",
+    ] {
+        assert!(e.format_number(bad, &ap, Position::SentenceStart).is_err());
+        assert!(e.format_number(bad, &ap, inline).is_err());
+    }
+
+    let start = Position::SentenceStart;
+    assert_eq!(e.format_number(12, &ap, start), Ok("Twelve".to_string()));
+    assert_eq!(
+        e.format_number(1250, &ap, start),
+        Ok("One thousand two hundred fifty".to_string())
+    );
+    assert_eq!(
+        e.format_number("2.5", &ap, start),
+        Ok("Two point five".to_string())
+    );
+
+    let custom = NumberStyle {
+        group_digits: false,
+        ..NumberStyle::custom(20)
+    };
+    assert_eq!(
+        e.format_number(20, &custom, inline),
+        Ok("twenty".to_string())
+    );
+    assert_eq!(
+        e.format_number(12345, &custom, inline),
+        Ok("12345".to_string())
+    );
+}
+
+#[test]
+fn test_cheque_words() {
+    let mut e = Engine::new();
+    let opts = ChequeOptions::default();
+    let cases = [
+        ("1250", "One Thousand Two Hundred Fifty and 00/100"),
+        ("1250.07", "One Thousand Two Hundred Fifty and 07/100"),
+        ("25.999", "Twenty-Six and 00/100"),
+        ("101", "One Hundred One and 00/100"),
+        ("0.5", "Zero and 50/100"),
+    ];
+    for (amount, words) in cases {
+        assert_eq!(e.cheque_words(amount, &opts), Ok(words.to_string()));
+    }
+    assert!(e.cheque_words("-5", &opts).is_err());
+    assert!(e.cheque_words("five", &opts).is_err());
+
+    let opts = ChequeOptions {
+        currency: e.currency("USD"),
+        casing: Casing::Upper,
+        width: Some(50),
+        ..Default::default()
+    };
+    let words = e.cheque_words(1_000_001, &opts).unwrap();
+    assert_eq!(words, "ONE MILLION ONE DOLLARS AND 00/100****************");
+    assert_eq!(words.len(), 50);
+    let opts = ChequeOptions {
+        currency: e.currency("JPY"),
+        ..Default::default()
+    };
+    assert_eq!(e.cheque_words(1, &opts), Ok("One Yen".to_string()));
+}
+
+#[test]
+fn test_roman() {
+    let mut e = Engine::new();
+    let upper = RomanStyle::default();
+    let cases = [
+        (1, "I"),
+        (4, "IV"),
+        (9, "IX"),
+        (14, "XIV"),
+        (40, "XL"),
+        (90, "XC"),
+        (400, "CD"),
+        (1994, "MCMXCIV"),
+        (2026, "MMXXVI"),
+        (3999, "MMMCMXCIX"),
+        (4000, "I\u{305}V\u{305}"),
+        (12_345, "X\u{305}I\u{305}I\u{305}CCCXLV"),
+    ];
+    for (n, numeral) in cases {
+        assert_eq!(e.to_roman(n, upper), Ok(numeral.to_string()));
+        assert_eq!(e.from_roman(numeral), Ok(n));
+    }
+    let lower = RomanStyle {
+        letters: RomanLetters::Lower,
+        ..Default::default()
+    };
+    assert_eq!(e.to_roman(14, lower), Ok("xiv".to_string()));
+    assert_eq!(e.from_roman("xiv"), Ok(14));
+    let unicode = RomanStyle {
+        letters: RomanLetters::Unicode,
+        ..Default::default()
+    };
+    assert_eq!(e.to_roman(14, unicode), Ok("ⅩⅠⅤ".to_string()));
+    assert_eq!(e.from_roman("ⅩⅠⅤ"), Ok(14));
+
+    let apostrophus = RomanStyle {
+        large: RomanLarge::Apostrophus,
+        ..Default::default()
+    };
+    assert_eq!(e.to_roman(5000, apostrophus), Ok("IↃↃ".to_string()));
+    assert_eq!(e.to_roman(4900, apostrophus), Ok("CIↃIↃↃCCIↃ".to_string()));
+    assert_eq!(e.from_roman("CIↃIↃↃCCIↃ"), Ok(4900));
+    let unicode_apostrophus = RomanStyle {
+        letters: RomanLetters::Unicode,
+        large: RomanLarge::Apostrophus,
+    };
+    assert_eq!(
+        e.to_roman(15_000, unicode_apostrophus),
+        Ok("ↂↁ".to_string())
+    );
+    assert_eq!(e.from_roman("ↂↁ"), Ok(15_000));
+    assert_eq!(e.to_roman(4000, unicode_apostrophus), Ok("ↀↁ".to_string()));
+    assert_eq!(
+        e.to_roman(9999, unicode_apostrophus),
+        Ok("ↀↂⅭↀⅩⅭⅠⅩ".to_string())
+    );
+    assert_eq!(e.from_roman("ↀↂⅭↀⅩⅭⅠⅩ"), Ok(9999));
+
+    assert!(e.to_roman(0, upper).is_err());
+    assert_eq!(
+        e.to_roman(4_000_000, upper),
+        Err(InflectError::NumOutOfRange)
+    );
+    assert_eq!(
+        e.to_roman(400_000, apostrophus),
+        Err(InflectError::NumOutOfRange)
+    );
+
+    for lenient in ["IIII", "IC", "MMMM", "xIv", "Ⅻ"] {
+        assert!(e.from_roman(lenient).is_err(), "{}", lenient);
+    }
+    assert_eq!(e.from_roman_lenient("IIII"), Ok(4));
+    assert_eq!(e.from_roman_lenient("IC"), Ok(99));
+    assert_eq!(e.from_roman_lenient("MMMM"), Ok(4000));
+    assert_eq!(e.from_roman_lenient("xIv"), Ok(14));
+    assert_eq!(e.from_roman_lenient("Ⅻ"), Ok(12));
+    assert!(e.from_roman_lenient("ABC").is_err());
+    assert!(e.from_roman_lenient("").is_err());
+
+    assert_eq!(
+        e.regnal_to_words("Henry VIII"),
+        Ok("Henry the Eighth".to_string())
+    );
+    assert_eq!(
+        e.regnal_to_words("Louis XIV of France"),
+        Ok("Louis the Fourteenth of France".to_string())
+    );
+    assert_eq!(
+        e.regnal_to_words("Elizabeth II."),
+        Ok("Elizabeth the Second.".to_string())
+    );
+    assert!(e.regnal_to_words("Henry").is_err());
+    assert_eq!(
+        e.regnal_to_words("Henry I"),
+        Ok("Henry the First".to_string())
+    );
+    assert!(e.regnal_to_words("Henry and I").is_err());
+    assert_eq!(
+        e.regnal_to_words("King Henry VIII"),
+        Ok("King Henry the Eighth".to_string())
+    );
+    assert_eq!(
+        e.regnal_to_words("Pope John XXIII"),
+        Ok("Pope John the Twenty-third".to_string())
+    );
+    assert_eq!(
+        e.regnal_to_words("Henry VIII's wives"),
+        Ok("Henry the Eighth's wives".to_string())
+    );
+    assert!(e.regnal_to_words("henry VIII").is_err());
+}
+
+#[test]
+fn test_ordinal() {
+    let e = Engine::new();
+    assert_eq!(e.ordinal(1), "1st");
+    assert_eq!(e.ordinal(11), "11th");
+    assert_eq!(e.ordinal(22), "22nd");
+    assert_eq!(e.ordinal(113), "113th");
+    assert_eq!(e.ordinal("twenty-one"), "twenty-first");
+    assert_eq!(e.ordinal("twelve"), "twelfth");
+    assert_eq!(e.ordinal("forty"), "fortieth");
+    assert_eq!(e.ordinal("four"), "fourth");
+}
+
+#[test]
+fn test_ordinal_words() {
+    let mut e = Engine::new();
+    let cases = [
+        ("0", "zeroth"),
+        ("1", "first"),
+        ("100", "one hundredth"),
+        ("113", "one hundred and thirteenth"),
+        ("1000000", "one millionth"),
+        ("2000000001", "two billion and first"),
+        ("-3", "minus third"),
+        ("1,000", "one thousandth"),
+    ];
+    for (n, words) in cases {
+        assert_eq!(e.ordinal_words(n), Ok(words.to_string()));
+    }
+    assert_eq!(e.ordinal_words(40u64), Ok("fortieth".to_string()));
+    assert!(e.ordinal_words("1.5").is_err());
+    assert!(e.ordinal_words("first").is_err());
+    assert_eq!(e.ordinal_words("+5"), Ok("fifth".to_string()));
+    assert_eq!(e.ordinal_words("1,000"), Ok("one thousandth".to_string()));
+    for bad in ["--5", "+-5", "1,0,0", "1,00"] {
+        assert!(e.ordinal_words(bad).is_err(), "{}", bad);
+    }
+
+    // Every scale word takes "-th", and the irregulars keep their forms after each scale.
+    let scales = NumberToWordsOptions::default();
+    for zeros in (3..=303).step_by(3) {
+        let power = format!("1{}", "0".repeat(zeros));
+        let scale = e.number_to_words(power.as_str(), &scales).unwrap();
+        assert_eq!(e.ordinal_words(power.as_str()), Ok(format!("{}th", scale)));
+        for (tail, ord) in [("05", "fifth"), ("09", "ninth"), ("12", "twelfth")] {
+            let n = format!("{}{}", &power[..power.len() - 2], tail);
+            let words = e.ordinal_words(n.as_str()).unwrap();
+            assert!(words.ends_with(&format!(" and {}", ord)), "{}", words);
+        }
+    }
+}
+
+#[test]
+fn test_multiplicatives() {
+    let mut e = Engine::new();
+    assert_eq!(e.times(1), Ok("once".to_string()));
+    assert_eq!(e.times(2), Ok("twice".to_string()));
+    assert_eq!(e.times(3), Ok("three times".to_string()));
+    assert_eq!(e.times(0), Ok("zero times".to_string()));
+    assert_eq!(e.times(21), Ok("twenty-one times".to_string()));
+    e.classical_dict.insert("ancient".to_string(), true);
+    assert_eq!(e.times(3), Ok("thrice".to_string()));
+    assert_eq!(e.times(4), Ok("four times".to_string()));
+
+    assert_eq!(e.tuple_word(1), Ok("single".to_string()));
+    assert_eq!(e.tuple_word(3), Ok("triple".to_string()));
+    assert_eq!(e.tuple_word(10), Ok("decuple".to_string()));
+    assert_eq!(e.tuple_word(11), Ok("eleven-tuple".to_string()));
+    assert_eq!(e.tuple_word(21), Ok("twenty-one-tuple".to_string()));
+    assert_eq!(e.tuple_word(100), Ok("100-tuple".to_string()));
+    assert_eq!(e.tuple_word(1234), Ok("1234-tuple".to_string()));
+    assert!(e.tuple_word(0).is_err());
+
+    assert_eq!(e.group_word(1), Ok("solo".to_string()));
+    assert_eq!(e.group_word(4), Ok("quartet".to_string()));
+    assert_eq!(e.group_word(12), Ok("group of twelve".to_string()));
+    assert!(e.group_word(0).is_err());
+}
+
+#[test]
+fn test_parse_ordinal() {
+    let mut e = Engine::new();
+    assert_eq!(e.parse_ordinal("113th"), Ok(113));
+    assert_eq!(e.parse_ordinal("1st"), Ok(1));
+    assert_eq!(e.parse_ordinal("11th"), Ok(11));
+    assert_eq!(e.parse_ordinal("1,000th"), Ok(1000));
+    assert_eq!(e.parse_ordinal("twenty-first"), Ok(21));
+    assert_eq!(e.parse_ordinal("Twelfth"), Ok(12));
+    assert_eq!(e.parse_ordinal("fortieth"), Ok(40));
+    assert_eq!(e.parse_ordinal("one hundred and twelfth"), Ok(112));
+    assert_eq!(e.parse_ordinal("a hundredth"), Ok(100));
+    assert_eq!(e.parse_ordinal("two millionth"), Ok(2_000_000));
+    assert_eq!(e.parse_ordinal("zeroth"), Ok(0));
+
+    assert!(e.parse_ordinal("22th").is_err());
+    assert!(e.parse_ordinal("11st").is_err());
+    assert!(e.parse_ordinal("3").is_err());
+    assert!(e.parse_ordinal("fiveth").is_err());
+    assert!(e.parse_ordinal("twenty-one").is_err());
+    assert!(e.parse_ordinal("").is_err());
+
+    let opts = NumberToWordsOptions::default();
+    for n in (0..300u64).chain([1011, 21_001, 1_000_000]) {
+        assert_eq!(e.parse_ordinal(&e.ordinal(n)), Ok(n));
+        let words = e.number_to_words(e.ordinal(n), &opts).unwrap();
+        assert_eq!(e.parse_ordinal(&words), Ok(n), "{}", words);
+    }
+}